@@ -0,0 +1,211 @@
+//! Parser of `symbols.yaml`, which is shared with the build script and `tools/render-icons`,
+//! so it must only depend on `yaml_rust2` and the `id` module.
+
+use yaml_rust2::{Yaml, YamlLoader};
+
+use crate::id;
+
+/// A symbol of `symbols.yaml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub command: String,
+    pub package: String,
+    pub font_encoding: String,
+    pub text_mode: bool,
+    pub math_mode: bool,
+    /// Other commands for the same glyph, which are not part of the symbol table.
+    pub aliases: Vec<String>,
+    /// Preferred command, if this command is an alias of another symbol in the table.
+    pub canonical: Option<String>,
+    /// Human-readable name, e.g. "rightwards double arrow".
+    pub description: Option<String>,
+    /// Additional words the symbol can be found by, e.g. "implies".
+    pub keywords: Vec<String>,
+    /// Table of `symbols.yaml` the symbol is listed in.
+    pub table: String,
+}
+
+impl Default for Symbol {
+    /// Returns the values of a symbol which are not set in `symbols.yaml`.
+    fn default() -> Self {
+        Self {
+            command: "".to_string(),
+            package: "latex2e".to_string(),
+            font_encoding: "OT1".to_string(),
+            text_mode: true,
+            math_mode: false,
+            aliases: Vec::new(),
+            canonical: None,
+            description: None,
+            keywords: Vec::new(),
+            table: "".to_string(),
+        }
+    }
+}
+
+impl Symbol {
+    pub fn id(&self) -> String {
+        id::id(&self.package, &self.font_encoding, &self.command)
+    }
+
+    /// Returns the file name of the icon in `data/resources/icons/symbols`.
+    pub fn icon(&self) -> String {
+        format!("{}-symbolic.svg", self.id())
+    }
+}
+
+/// Parses the symbols of `symbols.yaml`, in the order they are listed in, and the names of
+/// the tables.
+///
+/// Panics if the file is not valid, as nothing can be done without the symbols.
+pub fn parse(yaml: &str) -> (Vec<Symbol>, Vec<String>) {
+    let mut docs = YamlLoader::load_from_str(yaml).unwrap();
+    let doc = docs.pop().unwrap();
+
+    let mut symbols = Vec::new();
+    let mut tables = Vec::new();
+    for data in doc.into_vec().unwrap() {
+        // marks the start of a table, which all following symbols belong to
+        if let Some(table) = data["table"].as_str() {
+            assert!(
+                !tables.contains(&table.to_string()),
+                "Duplicate table {}",
+                table
+            );
+            tables.push(table.to_string());
+            continue;
+        }
+        let symbol = Symbol {
+            table: tables
+                .last()
+                .expect("Symbols should be listed after a table")
+                .clone(),
+            ..Symbol::default()
+        };
+        generate_symbol(data, symbol, &mut symbols);
+    }
+    (symbols, tables)
+}
+
+fn generate_symbol(data: Yaml, mut symbol: Symbol, symbols: &mut Vec<Symbol>) {
+    if let Some(cmd) = data.as_str() {
+        cmd.clone_into(&mut symbol.command);
+        assert!(
+            !symbol.command.is_empty(),
+            "Symbol does not have an associated command"
+        );
+        symbols.push(symbol);
+        return;
+    }
+
+    // the data should only contain strings and hashes
+    assert!(matches!(data, Yaml::Hash(_)));
+    let table = data.as_hash().unwrap();
+
+    // a single symbol with additional data
+    if let Some(command) = table.get(&Yaml::String("command".to_owned())) {
+        let Some(command) = command.as_str() else {
+            panic!("Command of {:?} should be a string", table);
+        };
+        command.clone_into(&mut symbol.command);
+        if let Some(aliases) = table.get(&Yaml::String("aliases".to_owned())) {
+            symbol.aliases = aliases
+                .as_vec()
+                .unwrap()
+                .iter()
+                .map(|alias| alias.as_str().unwrap().to_string())
+                .collect();
+        }
+        if let Some(canonical) = table.get(&Yaml::String("canonical".to_owned())) {
+            symbol.canonical = Some(canonical.as_str().unwrap().to_string());
+        }
+        if let Some(description) = table.get(&Yaml::String("description".to_owned())) {
+            symbol.description = Some(description.as_str().unwrap().to_string());
+        }
+        if let Some(keywords) = table.get(&Yaml::String("keywords".to_owned())) {
+            symbol.keywords = keywords
+                .as_vec()
+                .unwrap()
+                .iter()
+                .map(|keyword| keyword.as_str().unwrap().to_string())
+                .collect();
+        }
+        symbols.push(symbol);
+        return;
+    }
+
+    if let Some(package) = table.get(&Yaml::String("package".to_owned())) {
+        symbol.package = package.as_str().unwrap().to_string();
+    }
+
+    if let Some(fontenc) = table.get(&Yaml::String("fontenc".to_owned())) {
+        symbol.font_encoding = fontenc.as_str().unwrap().to_string();
+    }
+
+    for (mode, text_mode, math_mode) in [
+        ("bothmodes", true, true),
+        ("textmode", true, false),
+        ("mathmode", false, true),
+    ] {
+        symbol.text_mode = text_mode;
+        symbol.math_mode = math_mode;
+        if let Some(values) = table
+            .get(&Yaml::String(mode.to_owned()))
+            .cloned()
+            .and_then(|v| v.into_vec())
+        {
+            for mode_data in values {
+                generate_symbol(mode_data, symbol.clone(), symbols);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let yaml = r"
+- table: Letters
+- \textbar
+- command: \P
+  description: pilcrow
+- table: Arrows
+- package: stmaryrd
+  fontenc: T1
+  mathmode:
+    - \Mapsto
+  textmode:
+    - command: \textfoo
+";
+        let (symbols, tables) = parse(yaml);
+        assert_eq!(tables, vec!["Letters", "Arrows"]);
+        let commands: Vec<_> = symbols.iter().map(|s| s.command.as_str()).collect();
+        assert_eq!(commands, vec!["\\textbar", "\\P", "\\textfoo", "\\Mapsto"]);
+        assert_eq!(
+            symbols[0],
+            Symbol {
+                command: "\\textbar".to_owned(),
+                table: "Letters".to_owned(),
+                ..Symbol::default()
+            }
+        );
+        assert_eq!(symbols[1].description.as_deref(), Some("pilcrow"));
+        assert_eq!(symbols[3].package, "stmaryrd");
+        assert_eq!(symbols[3].font_encoding, "T1");
+        assert_eq!(symbols[3].table, "Arrows");
+        assert!(symbols[3].math_mode && !symbols[3].text_mode);
+        assert!(symbols[2].text_mode && !symbols[2].math_mode);
+    }
+
+    #[test]
+    fn test_icon() {
+        let symbol = Symbol {
+            command: "\\not\\approx".to_owned(),
+            ..Symbol::default()
+        };
+        assert_eq!(symbol.icon(), "latex2e-not-approx-symbolic.svg");
+    }
+}
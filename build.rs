@@ -1,55 +1,63 @@
-use base64::Engine;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
-use yaml_rust2::{Yaml, YamlLoader};
-
-#[derive(Debug, Clone)]
-struct Symbol {
-    command: String,
-    package: String,
-    font_encoding: String,
-    // these variables are used in the program, but not in the build process
-    #[allow(dead_code)]
-    text_mode: bool,
-    #[allow(dead_code)]
-    math_mode: bool,
-}
+use yaml_rust2::YamlLoader;
+
+#[path = "src/classify/id.rs"]
+mod id;
+#[path = "build-aux/symbols.rs"]
+mod symbols;
+
+use symbols::Symbol;
 
 impl Symbol {
-    fn id(&self) -> String {
-        let id = format!(
-            "{}-{}-{}",
+    fn legacy_id(&self) -> String {
+        id::legacy_id(&self.package, &self.font_encoding, &self.command)
+    }
+
+    /// Returns the code to construct the symbol.
+    fn to_code(&self) -> String {
+        format!(
+            "Symbol {{ command: {:?}, package: {:?}, font_encoding: {:?}, text_mode: {:?}, math_mode: {:?}, aliases: &{:?}, canonical: {:?}, description: {:?}, keywords: &{:?}, table: {:?} }}",
+            self.command,
             self.package,
             self.font_encoding,
-            self.command.replace('\\', "_")
-        );
-        base64::prelude::BASE64_STANDARD.encode(id)
+            self.text_mode,
+            self.math_mode,
+            self.aliases,
+            self.canonical.as_ref().unwrap_or(&self.command),
+            self.description,
+            self.keywords,
+            self.table
+        )
     }
 }
 
 fn main() {
     println!("cargo:rerun-if-changed=symbols.yaml");
+    println!("cargo:rerun-if-changed=confusables.yaml");
+    println!("cargo:rerun-if-changed=unicode.yaml");
+    println!("cargo:rerun-if-changed=data/labels.txt");
+    println!("cargo:rerun-if-changed=data/resources/icons/symbols");
     let out_dir = std::env::var("OUT_DIR").unwrap();
     let path = Path::new(&out_dir).join("symbol_table.rs");
     let mut file = BufWriter::new(File::create(path).unwrap());
 
     //TODO: replace this with a simple list?
     let mut map = phf_codegen::Map::new();
-    let mut docs = YamlLoader::load_from_str(include_str!("symbols.yaml")).unwrap();
-    let doc = docs.pop().unwrap();
-
-    // 'default' values for a symbol, i.e. it has not special table
-    let symbol = Symbol {
-        command: "".to_string(),
-        package: "latex2e".to_string(),
-        //TODO: remove? it is not displayed in the UI
-        font_encoding: "OT1".to_string(),
-        text_mode: true,
-        math_mode: false,
-    };
-    for data in doc.into_vec().unwrap() {
-        generate_symbol(data, symbol.clone(), &mut map);
+    let (mut symbols, tables) = symbols::parse(include_str!("symbols.yaml"));
+    resolve_aliases(&mut symbols);
+    share_descriptions(&mut symbols);
+    let mut ids = HashSet::new();
+    for symbol in &symbols {
+        assert!(
+            ids.insert(symbol.id()),
+            "Duplicate id {} of {}",
+            symbol.id(),
+            symbol.command
+        );
+        map.entry(symbol.id(), &symbol.to_code());
     }
 
     writeln!(
@@ -58,46 +66,247 @@ fn main() {
         map.build()
     )
     .unwrap();
+    writeln!(&mut file, "static TABLES: &[&str] = &{:?};", tables).unwrap();
+    generate_legacy_ids(&symbols, &mut file);
+
+    let path = Path::new(&out_dir).join("confusion_groups.rs");
+    let mut file = BufWriter::new(File::create(path).unwrap());
+    generate_confusion_groups(&symbols, &mut file);
+
+    let path = Path::new(&out_dir).join("unicode_table.rs");
+    let mut file = BufWriter::new(File::create(path).unwrap());
+    generate_unicode_table(&symbols, &mut file);
+
+    let path = Path::new(&out_dir).join("labels.rs");
+    let mut file = BufWriter::new(File::create(path).unwrap());
+    generate_labels(&symbols, &mut file);
+
+    check_icons(&symbols);
+}
+
+/// Checks that every symbol has an icon in `data/resources/icons/symbols` and that there are
+/// no icons of symbols which are no longer in the symbol table.
+///
+/// Both are fixed by running `tools/render-icons`, see the README.
+fn check_icons(symbols: &[Symbol]) {
+    let icons: HashSet<String> = std::fs::read_dir("data/resources/icons/symbols")
+        .expect("Failed to read icons")
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    let expected: HashSet<String> = symbols.iter().map(Symbol::icon).collect();
+
+    let mut orphans: Vec<&String> = icons.difference(&expected).collect();
+    orphans.sort();
+    assert!(
+        orphans.is_empty(),
+        "Icons in data/resources/icons/symbols are not in the symbol table, remove them with `tools/render-icons --prune`: {:?}",
+        orphans
+    );
+
+    let missing: Vec<&str> = symbols
+        .iter()
+        .filter(|symbol| !icons.contains(&symbol.icon()))
+        .map(|symbol| symbol.command.as_str())
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "{} symbols do not have an icon, render them with `tools/render-icons`: {}",
+        missing.len(),
+        missing.join(" ")
+    );
+}
+
+/// Writes the ids the symbols had before they became human-readable as a map to their current
+/// id, so stored and uploaded ids keep working.
+fn generate_legacy_ids(symbols: &[Symbol], file: &mut impl Write) {
+    let mut map = phf_codegen::Map::new();
+    for symbol in symbols {
+        map.entry(symbol.legacy_id(), &format!("{:?}", symbol.id()));
+    }
+    writeln!(
+        file,
+        "static LEGACY_IDS: phf::Map<&'static str, &'static str> = {};",
+        map.build()
+    )
+    .unwrap();
+}
+
+/// Writes the labels of the model from `data/labels.txt` as a static array of symbol ids,
+/// in the order of the outputs of the model.
+///
+/// The labels are the legacy ids the training data is labelled with.
+/// Fails if a label is not in the symbol table, as it could never be shown.
+fn generate_labels(symbols: &[Symbol], file: &mut impl Write) {
+    let ids: HashMap<String, String> = symbols
+        .iter()
+        .map(|symbol| (symbol.legacy_id(), symbol.id()))
+        .collect();
+    let labels: Vec<&str> = include_str!("data/labels.txt").lines().collect();
+
+    let mut seen = HashSet::new();
+    let unknown: Vec<String> = labels
+        .iter()
+        .enumerate()
+        .filter(|(_, label)| !ids.contains_key(**label) || !seen.insert(**label))
+        .map(|(line, label)| format!("{} (line {})", label, line + 1))
+        .collect();
+    assert!(
+        unknown.is_empty(),
+        "Labels in data/labels.txt are not in the symbol table or are duplicated: {}",
+        unknown.join(", ")
+    );
+
+    let labels: Vec<&String> = labels.iter().map(|label| &ids[*label]).collect();
+    writeln!(
+        file,
+        "/// Ids of the symbols the model can predict, in the order of its outputs.\npub(super) static LABELS: [&str; {}] = {:?};",
+        labels.len(),
+        labels
+    )
+    .unwrap();
 }
 
-fn generate_symbol(data: Yaml, mut symbol: Symbol, map: &mut phf_codegen::Map<String>) {
-    if let Some(cmd) = data.as_str() {
-        cmd.clone_into(&mut symbol.command);
+/// Writes the Unicode characters from `unicode.yaml` as a map from command to character.
+fn generate_unicode_table(symbols: &[Symbol], file: &mut impl Write) {
+    let mut docs = YamlLoader::load_from_str(include_str!("unicode.yaml")).unwrap();
+    let doc = docs.pop().unwrap();
+
+    let mut map = phf_codegen::Map::new();
+    for (command, character) in doc.into_hash().unwrap() {
+        let command = command.into_string().unwrap();
+        let character = character.into_string().unwrap();
         assert!(
-            !symbol.command.is_empty(),
-            "Symbol does not have an associated command"
+            symbols.iter().any(|symbol| symbol.command == command),
+            "Symbol {} with Unicode mapping is not in the symbol table",
+            command
         );
-        map.entry(symbol.id(), &format!("{:?}", symbol));
-        return;
+        let mut chars = character.chars();
+        let (Some(character), None) = (chars.next(), chars.next()) else {
+            panic!(
+                "Unicode mapping of {} should be a single character",
+                command
+            );
+        };
+        map.entry(command, &format!("{:?}", character));
     }
 
-    // the data should only contain strings and hashes
-    assert!(matches!(data, Yaml::Hash(_)));
-    let table = data.as_hash().unwrap();
+    writeln!(
+        file,
+        "static UNICODE_TABLE: phf::Map<&'static str, char> = {};",
+        map.build()
+    )
+    .unwrap();
+}
 
-    if let Some(package) = table.get(&Yaml::String("package".to_owned())) {
-        symbol.package = package.as_str().unwrap().to_string();
-    }
+/// Applies the aliases and canonical command of a symbol to all symbols with the same command,
+/// e.g. the same command in different packages.
+fn resolve_aliases(symbols: &mut [Symbol]) {
+    let declarations: Vec<(String, Vec<String>, Option<String>)> = symbols
+        .iter()
+        .filter(|symbol| !symbol.aliases.is_empty() || symbol.canonical.is_some())
+        .map(|symbol| {
+            (
+                symbol.command.clone(),
+                symbol.aliases.clone(),
+                symbol.canonical.clone(),
+            )
+        })
+        .collect();
+
+    for (command, aliases, canonical) in declarations {
+        for alias in &aliases {
+            assert!(
+                symbols.iter().all(|symbol| &symbol.command != alias),
+                "Alias {} of {} is in the symbol table, use `canonical` instead",
+                alias,
+                command
+            );
+        }
+        if let Some(canonical) = &canonical {
+            assert!(
+                symbols
+                    .iter()
+                    .any(|symbol| &symbol.command == canonical && symbol.canonical.is_none()),
+                "Canonical command {} of {} is not in the symbol table or is an alias itself",
+                canonical,
+                command
+            );
+        }
 
-    if let Some(fontenc) = table.get(&Yaml::String("fontenc".to_owned())) {
-        symbol.font_encoding = fontenc.as_str().unwrap().to_string();
+        for symbol in symbols
+            .iter_mut()
+            .filter(|symbol| symbol.command == command)
+        {
+            for alias in &aliases {
+                if !symbol.aliases.contains(alias) {
+                    symbol.aliases.push(alias.clone());
+                }
+            }
+            symbol.canonical = symbol.canonical.take().or(canonical.clone());
+        }
     }
+}
 
-    for (mode, text_mode, math_mode) in [
-        ("bothmodes", true, true),
-        ("textmode", true, false),
-        ("mathmode", false, true),
-    ] {
-        symbol.text_mode = text_mode;
-        symbol.math_mode = math_mode;
-        if let Some(values) = table
-            .get(&Yaml::String(mode.to_owned()))
-            .cloned()
-            .and_then(|v| v.into_vec())
+/// Applies the description and keywords of a symbol to all symbols with the same command,
+/// which do not have their own.
+fn share_descriptions(symbols: &mut [Symbol]) {
+    let declarations: Vec<(String, Option<String>, Vec<String>)> = symbols
+        .iter()
+        .filter(|symbol| symbol.description.is_some() || !symbol.keywords.is_empty())
+        .map(|symbol| {
+            (
+                symbol.command.clone(),
+                symbol.description.clone(),
+                symbol.keywords.clone(),
+            )
+        })
+        .collect();
+
+    for (command, description, keywords) in declarations {
+        for symbol in symbols
+            .iter_mut()
+            .filter(|symbol| symbol.command == command)
         {
-            for mode_data in values {
-                generate_symbol(mode_data, symbol.clone(), map);
+            if symbol.description.is_none() {
+                symbol.description.clone_from(&description);
+            }
+            if symbol.keywords.is_empty() {
+                symbol.keywords.clone_from(&keywords);
             }
         }
     }
 }
+
+/// Writes the groups of confusable symbols from `confusables.yaml` as a static slice
+/// of `(command, difference)` pairs.
+fn generate_confusion_groups(symbols: &[Symbol], file: &mut impl Write) {
+    let mut docs = YamlLoader::load_from_str(include_str!("confusables.yaml")).unwrap();
+    let doc = docs.pop().unwrap();
+
+    writeln!(
+        file,
+        "pub(super) static CONFUSION_GROUPS: &[&[(&str, &str)]] = &["
+    )
+    .unwrap();
+    for group in doc.into_vec().unwrap() {
+        let group = group.into_hash().expect("Confusion group should be a map");
+        assert!(
+            group.len() >= 2,
+            "Confusion group should contain at least two symbols"
+        );
+
+        write!(file, "    &[").unwrap();
+        for (command, difference) in group {
+            let command = command.into_string().unwrap();
+            let difference = difference.into_string().unwrap();
+            assert!(
+                symbols.iter().any(|symbol| symbol.command == command),
+                "Confusable symbol {} is not in the symbol table",
+                command
+            );
+            write!(file, "({:?}, {:?}), ", command, difference).unwrap();
+        }
+        writeln!(file, "],").unwrap();
+    }
+    writeln!(file, "];").unwrap();
+}
@@ -0,0 +1,370 @@
+/*
+ * Descriptions and keywords of the symbols and names of the tables in symbols.yaml,
+ * and the differences between the confusable symbols in confusables.yaml.
+ *
+ * This file is only used to extract the strings for translation, as xgettext does not
+ * support YAML. The test `test_strings_are_translatable` in src/classify/symbol.rs lists
+ * the lines that are missing after changing either file.
+ */
+
+/* Translators: These are names of mathematical symbols and words to search for them. */
+N_("dollar sign");
+N_("underscore");
+N_("left brace");
+N_("pilcrow");
+N_("copyright sign");
+N_("ellipsis");
+N_("right brace");
+N_("section sign");
+N_("pound sign");
+N_("ampersand");
+N_("number sign");
+N_("percent sign");
+N_("check mark");
+N_("amalgamation");
+N_("union");
+N_("circled plus");
+N_("multiplication sign");
+N_("asterisk");
+N_("dagger");
+N_("circled slash");
+N_("double dagger");
+N_("circled times");
+N_("plus-minus sign");
+N_("division sign");
+N_("bullet");
+N_("set minus");
+N_("intersection");
+N_("minus-plus sign");
+N_("logical or");
+N_("centered dot");
+N_("circled dot");
+N_("logical and");
+N_("ring operator");
+N_("circled minus");
+N_("star");
+N_("large intersection");
+N_("large circled times");
+N_("large logical and");
+N_("product");
+N_("large union");
+N_("coproduct");
+N_("sum");
+N_("integral");
+N_("large circled plus");
+N_("large logical or");
+N_("contour integral");
+N_("double integral");
+N_("triple integral");
+N_("almost equal to");
+N_("identical to");
+N_("perpendicular");
+N_("equivalent to");
+N_("precedes");
+N_("succeeds");
+N_("precedes or equal to");
+N_("succeeds or equal to");
+N_("approximately equal to");
+N_("divides");
+N_("proportional to");
+N_("right tack");
+N_("left tack");
+N_("models");
+N_("tilde operator");
+N_("approaches the limit");
+N_("parallel to");
+N_("asymptotically equal to");
+N_("therefore");
+N_("because");
+N_("superset of");
+N_("subset of");
+N_("superset of or equal to");
+N_("subset of or equal to");
+N_("subset of with not equal to");
+N_("greater-than or equal to");
+N_("much greater-than");
+N_("less-than or equal to");
+N_("much less-than");
+N_("not equal to");
+N_("greater-than or slanted equal to");
+N_("less-than or slanted equal to");
+N_("downwards double arrow");
+N_("long leftwards arrow");
+N_("north west arrow");
+N_("downwards arrow");
+N_("long leftwards double arrow");
+N_("rightwards double arrow");
+N_("leftwards arrow with hook");
+N_("long left right arrow");
+N_("rightwards arrow");
+N_("rightwards arrow with hook");
+N_("long left right double arrow");
+N_("south east arrow");
+N_("long rightwards arrow from bar");
+N_("south west arrow");
+N_("leftwards arrow");
+N_("long rightwards double arrow");
+N_("upwards arrow");
+N_("leftwards double arrow");
+N_("long rightwards arrow");
+N_("upwards double arrow");
+N_("left right double arrow");
+N_("rightwards arrow from bar");
+N_("up down arrow");
+N_("left right arrow");
+N_("north east arrow");
+N_("up down double arrow");
+N_("alpha");
+N_("theta");
+N_("tau");
+N_("beta");
+N_("theta variant");
+N_("pi");
+N_("upsilon");
+N_("gamma");
+N_("iota");
+N_("pi variant");
+N_("phi");
+N_("delta");
+N_("kappa");
+N_("rho");
+N_("phi variant");
+N_("epsilon");
+N_("lambda");
+N_("rho variant");
+N_("chi");
+N_("epsilon variant");
+N_("mu");
+N_("sigma");
+N_("psi");
+N_("zeta");
+N_("nu");
+N_("final sigma");
+N_("omega");
+N_("eta");
+N_("xi");
+N_("capital gamma");
+N_("capital lambda");
+N_("capital sigma");
+N_("capital psi");
+N_("capital delta");
+N_("capital xi");
+N_("capital upsilon");
+N_("capital omega");
+N_("capital theta");
+N_("capital pi");
+N_("capital phi");
+N_("up tack");
+N_("for all");
+N_("dotless i");
+N_("contains as member");
+N_("down tack");
+N_("script small l");
+N_("reduced Planck constant");
+N_("element of");
+N_("not an element of");
+N_("partial differential");
+N_("Weierstrass p");
+N_("there exists");
+N_("imaginary part");
+N_("dotless j");
+N_("real part");
+N_("complement");
+N_("there does not exist");
+N_("colon");
+N_("vertical ellipsis");
+N_("midline horizontal ellipsis");
+N_("down right diagonal ellipsis");
+N_("aleph");
+N_("infinity");
+N_("prime");
+N_("angle");
+N_("diamond suit");
+N_("sharp");
+N_("backslash");
+N_("empty set");
+N_("nabla");
+N_("spade suit");
+N_("flat");
+N_("natural");
+N_("square root");
+N_("club suit");
+N_("heart suit");
+N_("not sign");
+N_("triangle");
+N_("white square");
+N_("black square");
+N_("empty set variant");
+N_("double-struck one");
+N_("currency");
+N_("curly bracket");
+N_("paragraph");
+N_("three dots");
+N_("sterling");
+N_("and");
+N_("hash");
+N_("tick");
+N_("set");
+N_("direct sum");
+N_("exclusive or");
+N_("times");
+N_("cross product");
+N_("adjoint");
+N_("tensor product");
+N_("obelus");
+N_("dot");
+N_("difference");
+N_("disjunction");
+N_("join");
+N_("multiplication");
+N_("dot product");
+N_("conjunction");
+N_("meet");
+N_("composition");
+N_("summation");
+N_("line integral");
+N_("surface integral");
+N_("volume integral");
+N_("approximately");
+N_("congruent");
+N_("equivalent");
+N_("orthogonal");
+N_("asymptotic");
+N_("isomorphic");
+N_("vertical bar");
+N_("such that");
+N_("proves");
+N_("turnstile");
+N_("entails");
+N_("satisfies");
+N_("similar");
+N_("distributed as");
+N_("dot equal");
+N_("homotopy equivalent");
+N_("proper subset");
+N_("inequality");
+N_("unequal");
+N_("diagonal");
+N_("implied by");
+N_("implies");
+N_("to");
+N_("maps to");
+N_("tends to");
+N_("embedding");
+N_("injection");
+N_("if and only if");
+N_("gets");
+N_("assignment");
+N_("greek");
+N_("micro");
+N_("ohm");
+N_("bottom");
+N_("false");
+N_("falsum");
+N_("universal quantifier");
+N_("top");
+N_("true");
+N_("length");
+N_("h bar");
+N_("member");
+N_("derivative");
+N_("existential quantifier");
+N_("black-letter I");
+N_("black-letter R");
+N_("hebrew");
+N_("cardinal");
+N_("apostrophe");
+N_("cards");
+N_("music");
+N_("null");
+N_("del");
+N_("gradient");
+N_("radical");
+N_("negation");
+N_("box");
+N_("end of proof");
+N_("qed");
+N_("indicator function");
+
+/* Translators: These are names of tables from the Comprehensive LaTeX Symbol List. */
+N_("Predefined LaTeX 2ε Text-mode Commands");
+N_("LaTeX 2ε Commands Defined to Work in Both Math and Text Mode");
+N_("AMS Commands Defined to Work in Both Math and Text Mode");
+N_("Non-ASCII Letters (Excluding Accented Letters)");
+N_("Punctuation Marks Not Found in OT1");
+N_("tipa Phonetic Symbols");
+N_("textcomp Currency Symbols");
+N_("marvosym Currency Symbols");
+N_("wasysym Currency Symbols");
+N_("textcomp Legal Symbols");
+N_("Miscellaneous textcomp Symbols");
+N_("Miscellaneous wasysym Text-mode Symbols");
+N_("Math-Mode Versions of Text Symbols");
+N_("Binary Operators");
+N_("AMS Binary Operators");
+N_("wasysym Binary Operators");
+N_("Variable-sized Math Operators");
+N_("cmll Binary Operators");
+N_("AMS Variable-sized Math Operators");
+N_("esint Variable-sized Math Operators");
+N_("Binary Relations");
+N_("AMS Binary Relations");
+N_("AMS Negated Binary Relations");
+N_("stmaryrd Binary Relations");
+N_("Subset and Superset Relations");
+N_("AMS Subset and Superset Relations");
+N_("Inequalities");
+N_("AMS Inequalities");
+N_("AMS Triangle Relations");
+N_("Arrows");
+N_("Harpoons");
+N_("textcomp Text-mode Arrows");
+N_("AMS Arrows");
+N_("AMS Negated Arrows");
+N_("AMS Harpoons");
+N_("stmaryrd Arrows");
+N_("Greek Letters");
+N_("AMS Greek Letters");
+N_("upgreek Upright Greek Letters");
+N_("Letter-like Symbols");
+N_("AMS Letter-like Symbols");
+N_("stmaryrd Delimiters");
+N_("Variable-sized Delimiters");
+N_("stmaryrd Variable-sized Delimiters");
+N_("textcomp Text-mode Delimiters");
+N_("Dots");
+N_("AMS Dots");
+N_("Miscellaneous LaTeX 2ε Math Symbols");
+N_("Miscellaneous AMS Math Symbols");
+N_("Miscellaneous textcomp Text-mode Math Symbols");
+N_("Math Alphabets");
+N_("gensymb Symbols Defined to Work in Both Math and Text Mode");
+N_("wasysym Astronomical Symbols");
+N_("marvosym Astronomical Symbols");
+N_("marvosym Communication Symbols");
+N_("wasysym Biological Symbols");
+N_("wasysym General Symbols");
+N_("Other marvosym Symbols");
+
+/* Translators: These describe how a symbol differs from similar looking symbols. */
+N_("Straight, like the element sign");
+N_("Rounded, like a reversed 3");
+N_("Closed, with a vertical stroke through it");
+N_("Open loop, drawn in one stroke");
+N_("Closed oval with a horizontal bar");
+N_("Cursive, open at the top right");
+N_("Straight descender");
+N_("Descender curving to the left");
+N_("Straight legs");
+N_("Wide, with a bar over a curved ω");
+N_("Closed loop with a short stroke");
+N_("Final form, open with a tail");
+N_("Narrow oval, like a slashed zero");
+N_("Round circle with a long slash");
+N_("Horizontal lower bar");
+N_("Lower bar parallel to the upper stroke");
+N_("Slash through the lower bar only");
+N_("Slash through a split lower bar");
+N_("Binary operator, full height");
+N_("Binary operator, only x-height");
+N_("Ordinary symbol, like a delimiter");
@@ -1,4 +1,9 @@
+use std::ffi::OsStr;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
 use adw::prelude::AdwDialogExt;
+use gettextrs::gettext;
 use tracing::{debug, info};
 
 use gtk::prelude::*;
@@ -8,6 +13,7 @@ use gtk::{gdk, gio, glib};
 use crate::config::{self};
 use crate::widgets::about;
 use crate::window::HieroglyphicWindow;
+use crate::{classify, export};
 
 mod imp {
     use super::*;
@@ -27,7 +33,12 @@ mod imp {
         type ParentType = adw::Application;
     }
 
-    impl ObjectImpl for HieroglyphicApplication {}
+    impl ObjectImpl for HieroglyphicApplication {
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().setup_command_line();
+        }
+    }
 
     impl ApplicationImpl for HieroglyphicApplication {
         fn activate(&self) {
@@ -49,6 +60,20 @@ mod imp {
             app.main_window().present();
         }
 
+        fn handle_local_options(&self, options: &glib::VariantDict) -> ControlFlow<glib::ExitCode> {
+            if let Ok(Some(input)) = options.lookup::<PathBuf>("export") {
+                let output = options
+                    .lookup::<PathBuf>("output")
+                    .ok()
+                    .flatten()
+                    .unwrap_or_else(|| input.with_extension("svg"));
+                let scale = options.lookup::<f64>("scale").ok().flatten().unwrap_or(1.0);
+                return ControlFlow::Break(export_recording(&input, &output, scale));
+            }
+
+            self.parent_handle_local_options(options)
+        }
+
         fn startup(&self) {
             debug!("Hieroglyphic<HieroglyphicApplication>::startup");
             self.parent_startup();
@@ -97,11 +122,44 @@ impl HieroglyphicApplication {
         self.add_action_entries([action_quit, action_about]);
     }
 
+    // Sets up command line options
+    fn setup_command_line(&self) {
+        self.add_main_option(
+            "export",
+            glib::Char::from(b'e'),
+            glib::OptionFlags::NONE,
+            glib::OptionArg::Filename,
+            &gettext("Export a recorded drawing as SVG or PNG"),
+            Some("FILE"),
+        );
+        self.add_main_option(
+            "output",
+            glib::Char::from(b'o'),
+            glib::OptionFlags::NONE,
+            glib::OptionArg::Filename,
+            &gettext("File to export to, ending in .svg or .png"),
+            Some("FILE"),
+        );
+        self.add_main_option(
+            "scale",
+            glib::Char::from(b's'),
+            glib::OptionFlags::NONE,
+            glib::OptionArg::Double,
+            &gettext("Scale of the exported image"),
+            Some("SCALE"),
+        );
+    }
+
     // Sets up keyboard shortcuts
     fn setup_accels(&self) {
         self.set_accels_for_action("app.quit", &["<Control>q"]);
         self.set_accels_for_action("window.close", &["<Control>w"]);
-        self.set_accels_for_action("win.clear", &["<Control>n", "Delete"]);
+        // `Delete` and `<Control>v` are window shortcuts, see `HieroglyphicWindow::setup_shortcuts`
+        self.set_accels_for_action("win.clear", &["<Control>n"]);
+        self.set_accels_for_action("win.open-drawing", &["<Control>o"]);
+        self.set_accels_for_action("win.save-drawing", &["<Control>s"]);
+        self.set_accels_for_action("win.fullscreen", &["F11"]);
+        self.set_accels_for_action("win.search", &["<Control>f"]);
     }
 
     fn setup_css(&self) {
@@ -129,6 +187,39 @@ impl HieroglyphicApplication {
     }
 }
 
+/// Exports the recorded drawing from `input` to `output`.
+///
+/// The format is chosen based on the extension of `output`, either SVG or PNG.
+fn export_recording(input: &Path, output: &Path, scale: f64) -> glib::ExitCode {
+    let result = std::fs::read(input)
+        .map_err(|err| err.to_string())
+        .and_then(|data| classify::Recording::from_json(&data).map_err(|err| err.to_string()))
+        .and_then(|recording| {
+            let strokes = recording.strokes();
+            let data = match output.extension().and_then(OsStr::to_str) {
+                Some("svg") => export::svg(&strokes, recording.width, recording.height, scale)
+                    .map_err(|err| err.to_string())?
+                    .into_bytes(),
+                Some("png") => export::png(&strokes, recording.width, recording.height, scale)
+                    .map_err(|err| err.to_string())?
+                    .to_vec(),
+                _ => return Err(gettext("Unsupported file format, expected .svg or .png")),
+            };
+            std::fs::write(output, data).map_err(|err| err.to_string())
+        });
+
+    match result {
+        Ok(()) => {
+            info!("Exported {} to {}", input.display(), output.display());
+            glib::ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}: {}", gettext("Failed to export drawing"), err);
+            glib::ExitCode::FAILURE
+        }
+    }
+}
+
 impl Default for HieroglyphicApplication {
     fn default() -> Self {
         glib::Object::builder()
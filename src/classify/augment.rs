@@ -0,0 +1,257 @@
+use super::{point::Point, rect::Rect, Stroke};
+
+/// Small deterministic pseudo-random number generator (SplitMix64).
+///
+/// Augmentations have to be reproducible for a given seed, independent of the platform or
+/// dependency versions, so a tiny self-contained generator is used.
+#[derive(Clone, Debug)]
+pub(super) struct SeededRng(u64);
+
+impl SeededRng {
+    /// Create a new generator from the given `seed`.
+    pub(super) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the next random `u64`.
+    pub(super) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random float in `[0, 1)`.
+    pub(super) fn next_f64(&mut self) -> f64 {
+        // use the upper 53 bits, which fit exactly into the mantissa
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random float in `[-max, max)`.
+    pub(super) fn symmetric(&mut self, max: f64) -> f64 {
+        (self.next_f64() * 2.0 - 1.0) * max
+    }
+
+    /// Returns a random index in `[0, n)`.
+    pub(super) fn index(&mut self, n: usize) -> usize {
+        (self.next_f64() * n as f64) as usize
+    }
+}
+
+/// Random perturbations of a drawing, used to generate more varied training samples.
+///
+/// All operations are deterministic given their `seed`.
+pub trait Augment {
+    /// Returns a mutable iterator over all points.
+    fn points_mut(&mut self) -> impl Iterator<Item = &mut Point>;
+
+    /// Rotates all points around their center by a random angle in `[-max_angle, max_angle]`
+    /// (in radians).
+    fn rotate(&mut self, max_angle: f64, seed: u64) {
+        let Some(bb) = bounds(self.points_mut()) else {
+            return;
+        };
+        let center = (bb.lower_left + bb.upper_right) * 0.5;
+        let (sin, cos) = SeededRng::new(seed).symmetric(max_angle).sin_cos();
+        for point in self.points_mut() {
+            let Point { x, y } = *point - center;
+            *point = Point {
+                x: x * cos - y * sin,
+                y: x * sin + y * cos,
+            } + center;
+        }
+    }
+
+    /// Shears all points horizontally around their center by a random factor in
+    /// `[-max_shear, max_shear]`.
+    fn shear(&mut self, max_shear: f64, seed: u64) {
+        let Some(bb) = bounds(self.points_mut()) else {
+            return;
+        };
+        let center = (bb.lower_left + bb.upper_right) * 0.5;
+        let factor = SeededRng::new(seed).symmetric(max_shear);
+        for point in self.points_mut() {
+            point.x += factor * (point.y - center.y);
+        }
+    }
+
+    /// Scales all points around their center, independently in x and y,
+    /// by a random factor in `[1 - max_scale, 1 + max_scale]`.
+    fn scale_jitter(&mut self, max_scale: f64, seed: u64) {
+        let Some(bb) = bounds(self.points_mut()) else {
+            return;
+        };
+        let center = (bb.lower_left + bb.upper_right) * 0.5;
+        let mut rng = SeededRng::new(seed);
+        let scale_x = 1.0 + rng.symmetric(max_scale);
+        let scale_y = 1.0 + rng.symmetric(max_scale);
+        for point in self.points_mut() {
+            *point = (*point - center).scale(scale_x, scale_y) + center;
+        }
+    }
+
+    /// Moves every point by a random offset of at most `amount` in each direction.
+    ///
+    /// The `amount` is relative to the larger side of the bounding box, so the noise
+    /// is independent of the canvas size.
+    fn noise(&mut self, amount: f64, seed: u64) {
+        let Some(bb) = bounds(self.points_mut()) else {
+            return;
+        };
+        let amount = amount * bb.width().max(bb.height());
+        let mut rng = SeededRng::new(seed);
+        for point in self.points_mut() {
+            point.x += rng.symmetric(amount);
+            point.y += rng.symmetric(amount);
+        }
+    }
+}
+
+/// Augmentations that only make sense for a whole sample, i.e. multiple strokes.
+pub trait AugmentStrokes {
+    /// Randomly removes each stroke with the given `probability`.
+    ///
+    /// At least one stroke is always kept, so the sample never becomes empty.
+    fn drop_strokes(&mut self, probability: f64, seed: u64);
+
+    /// Randomly shuffles the order of the strokes.
+    fn shuffle_strokes(&mut self, seed: u64);
+}
+
+impl Augment for Stroke {
+    fn points_mut(&mut self) -> impl Iterator<Item = &mut Point> {
+        self.points_mut()
+    }
+}
+
+impl Augment for Vec<Stroke> {
+    fn points_mut(&mut self) -> impl Iterator<Item = &mut Point> {
+        self.iter_mut().flat_map(|stroke| stroke.points_mut())
+    }
+}
+
+impl AugmentStrokes for Vec<Stroke> {
+    fn drop_strokes(&mut self, probability: f64, seed: u64) {
+        if self.len() < 2 {
+            return;
+        }
+        let mut rng = SeededRng::new(seed);
+        let keep = rng.index(self.len());
+        let mut index = 0;
+        self.retain(|_| {
+            let retain = index == keep || rng.next_f64() >= probability;
+            index += 1;
+            retain
+        });
+    }
+
+    fn shuffle_strokes(&mut self, seed: u64) {
+        let mut rng = SeededRng::new(seed);
+        // Fisher–Yates shuffle
+        for i in (1..self.len()).rev() {
+            self.swap(i, rng.index(i + 1));
+        }
+    }
+}
+
+/// Computes the bounding box of the given points, or `None` if there are no points.
+fn bounds<'a>(mut points: impl Iterator<Item = &'a mut Point>) -> Option<Rect> {
+    let first = *points.next()?;
+    Some(points.fold(Rect::from_point(first), |mut bb, point| {
+        bb.encompass_point(point);
+        bb
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Stroke> {
+        vec![
+            Stroke::new(vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 10.0, y: 10.0 },
+                Point { x: 20.0, y: 0.0 },
+            ]),
+            Stroke::new(vec![Point { x: 5.0, y: 5.0 }, Point { x: 15.0, y: 5.0 }]),
+            Stroke::new(vec![Point { x: 10.0, y: 0.0 }, Point { x: 10.0, y: 10.0 }]),
+        ]
+    }
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = SeededRng::new(42);
+        for _ in 0..1000 {
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+            assert!(rng.index(7) < 7);
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let augment = |seed| {
+            let mut strokes = sample();
+            strokes.rotate(0.2, seed);
+            strokes.shear(0.2, seed);
+            strokes.scale_jitter(0.1, seed);
+            strokes.noise(0.02, seed);
+            strokes.drop_strokes(0.5, seed);
+            strokes.shuffle_strokes(seed);
+            strokes
+        };
+        assert_eq!(augment(7), augment(7));
+        assert_ne!(augment(7), augment(8));
+    }
+
+    #[test]
+    fn test_rotate_keeps_center() {
+        let mut stroke = Stroke::new(vec![Point { x: -1.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }]);
+        stroke.rotate(std::f64::consts::PI, 3);
+        let mut points = stroke.points();
+        let (p, q) = (*points.next().unwrap(), *points.next().unwrap());
+        assert!(((p + q) * 0.5).approx_eq(Point { x: 0.0, y: 0.0 }));
+        assert!((p.euclidean_distance(&q) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_zero_amount_is_identity() {
+        let mut strokes = sample();
+        strokes.rotate(0.0, 1);
+        strokes.shear(0.0, 1);
+        strokes.scale_jitter(0.0, 1);
+        strokes.noise(0.0, 1);
+        strokes.drop_strokes(0.0, 1);
+        assert_eq!(strokes, sample());
+    }
+
+    #[test]
+    fn test_drop_strokes_keeps_one() {
+        let mut strokes = sample();
+        strokes.drop_strokes(1.0, 5);
+        assert_eq!(strokes.len(), 1);
+        assert!(sample().contains(&strokes[0]));
+    }
+
+    #[test]
+    fn test_shuffle_strokes_is_permutation() {
+        let mut strokes = sample();
+        strokes.shuffle_strokes(11);
+        assert_eq!(strokes.len(), sample().len());
+        for stroke in sample() {
+            assert!(strokes.contains(&stroke));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut strokes: Vec<Stroke> = Vec::new();
+        strokes.rotate(1.0, 1);
+        strokes.noise(1.0, 1);
+        strokes.drop_strokes(1.0, 1);
+        strokes.shuffle_strokes(1);
+        assert!(strokes.is_empty());
+    }
+}
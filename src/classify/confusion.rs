@@ -0,0 +1,99 @@
+use super::Symbol;
+
+include!(concat!(env!("OUT_DIR"), "/confusion_groups.rs"));
+
+/// Number of top results that are checked for confusable symbols.
+const CONFUSION_DEPTH: usize = 5;
+
+/// Returns the confusable symbols among the top results, together with what sets each of them
+/// apart from the others.
+///
+/// The result is only non-empty if the two most likely symbols are different members of the
+/// same confusion group, as defined in `confusables.yaml`. The symbols keep their order and
+/// each command only appears once.
+pub fn confusables(ids: &[&'static str]) -> Vec<(&'static str, &'static str)> {
+    let command_of = |id: &str| Symbol::from_id(id).map(|symbol| symbol.command);
+    let [first, second, ..] = ids else {
+        return Vec::new();
+    };
+    let (Some(first), Some(second)) = (command_of(first), command_of(second)) else {
+        return Vec::new();
+    };
+    if first == second {
+        return Vec::new();
+    }
+
+    let Some(group) = CONFUSION_GROUPS.iter().find(|group| {
+        group.iter().any(|(command, _)| *command == first)
+            && group.iter().any(|(command, _)| *command == second)
+    }) else {
+        return Vec::new();
+    };
+
+    let mut confusables: Vec<(&'static str, &'static str)> = Vec::new();
+    for &id in ids.iter().take(CONFUSION_DEPTH) {
+        let Some(command) = command_of(id) else {
+            continue;
+        };
+        let Some(&(_, difference)) = group.iter().find(|(member, _)| *member == command) else {
+            continue;
+        };
+        if confusables
+            .iter()
+            .all(|&(other, _)| command_of(other) != Some(command))
+        {
+            confusables.push((id, difference));
+        }
+    }
+    confusables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the id of the `latex2e` symbol with the given command.
+    fn id(command: &str) -> &'static str {
+        Symbol::from_id(&crate::classify::id::id("latex2e", "OT1", command))
+            .unwrap()
+            .id()
+    }
+
+    #[test]
+    fn test_groups_are_valid() {
+        for group in CONFUSION_GROUPS {
+            assert!(group.len() >= 2);
+            for (_, difference) in group.iter() {
+                assert!(!difference.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_confusables() {
+        let (epsilon, varepsilon) = (id("\\epsilon"), id("\\varepsilon"));
+        let other = id("\\alpha");
+
+        assert_eq!(
+            confusables(&[varepsilon, epsilon, other]),
+            vec![
+                (varepsilon, "Rounded, like a reversed 3"),
+                (epsilon, "Straight, like the element sign")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_confusables() {
+        let (epsilon, varepsilon) = (id("\\epsilon"), id("\\varepsilon"));
+        let other = id("\\alpha");
+
+        assert!(confusables(&[]).is_empty());
+        assert!(confusables(&[epsilon]).is_empty());
+        // the two most likely symbols have to be confusable
+        assert!(confusables(&[epsilon, other, varepsilon]).is_empty());
+        assert!(confusables(&[epsilon, epsilon]).is_empty());
+        // different groups
+        assert!(confusables(&[epsilon, id("\\varphi")]).is_empty());
+    }
+}
@@ -0,0 +1,476 @@
+use std::sync::{Mutex, RwLock};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    augment::Augment,
+    id,
+    point::{Point, ZERO_POINT},
+    Stroke, Symbol,
+};
+
+/// Version of the custom symbols file format written by this version of the app.
+const VERSION: u32 = 1;
+
+/// Font encoding of custom symbols, which keeps their ids apart from the symbol table.
+pub(super) const FONT_ENCODING: &str = "custom";
+/// Table custom symbols are listed in.
+pub(super) const TABLE: &str = "Custom Symbols";
+/// Icon shown for all custom symbols.
+pub(super) const ICON_NAME: &str = "document-edit-symbolic";
+
+/// Number of points each drawing is resampled to for matching.
+const MATCH_POINTS: usize = 32;
+/// Maximal distance between a drawing and an example, for the drawing to match the example.
+///
+/// Drawings are normalized to the unit square, so this is relative to their size.
+const MAX_DISTANCE: f64 = 0.05;
+/// Number of top results of the model, among which matching custom symbols are inserted.
+const MATCH_DEPTH: usize = 5;
+/// Number of distorted copies matched in addition to each example, so a few examples suffice.
+const AUGMENTATIONS: u64 = 4;
+
+/// A symbol defined by the user, e.g. a macro of their own preamble or a symbol of a package
+/// that is not part of the symbol table.
+///
+/// Custom symbols are only stored locally and recognized by comparing the drawing to their
+/// examples, see [`match_custom_symbols`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CustomSymbol {
+    /// Command to display the symbol.
+    pub command: String,
+    /// Package which the symbol belongs to, `None` if it does not need one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// Whether the symbol is available in text mode.
+    pub text_mode: bool,
+    /// Whether the symbol is available in math mode.
+    pub math_mode: bool,
+    /// Example drawings of the symbol.
+    pub examples: Vec<Vec<Stroke>>,
+}
+
+/// Stored list of custom symbols.
+#[derive(Serialize, Deserialize)]
+struct CustomSymbols {
+    version: u32,
+    symbols: Vec<CustomSymbol>,
+}
+
+impl CustomSymbol {
+    /// Parses a list of custom symbols from JSON.
+    ///
+    /// Fails if the data is not valid or uses a newer version of the format.
+    pub fn list_from_json(data: &[u8]) -> serde_json::Result<Vec<Self>> {
+        let list: CustomSymbols = serde_json::from_slice(data)?;
+        if list.version > VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported custom symbols version {}",
+                list.version
+            )));
+        }
+        Ok(list.symbols)
+    }
+
+    /// Serializes the list of custom symbols to JSON.
+    pub fn list_to_json(symbols: &[Self]) -> String {
+        let list = CustomSymbols {
+            version: VERSION,
+            symbols: symbols.to_vec(),
+        };
+        serde_json::to_string_pretty(&list).expect(
+            "Custom symbols only contain strings and plain numbers, so serializing cannot fail",
+        )
+    }
+
+    /// Returns the id of the custom symbol, i.e. the id of its [`Symbol`].
+    ///
+    /// Custom symbols with the same id replace each other.
+    pub fn id(&self) -> String {
+        id::id(self.package(), FONT_ENCODING, &self.command)
+    }
+
+    /// Returns the package of the symbol, `latex2e` if it does not need one.
+    fn package(&self) -> &str {
+        self.package.as_deref().unwrap_or("latex2e")
+    }
+
+    /// Returns the [`Symbol`] of the custom symbol.
+    fn symbol(&self) -> Symbol {
+        Symbol::new_custom(
+            intern(&self.command),
+            intern(self.package()),
+            self.text_mode,
+            self.math_mode,
+        )
+    }
+}
+
+/// A registered custom symbol.
+struct Entry {
+    id: &'static str,
+    symbol: &'static Symbol,
+    custom: CustomSymbol,
+    /// Normalized examples, including their distorted copies.
+    examples: Vec<Vec<Point>>,
+}
+
+impl Entry {
+    fn new(custom: CustomSymbol) -> Self {
+        let symbol = intern_symbol(custom.symbol());
+        Self {
+            id: symbol.id(),
+            symbol,
+            examples: custom
+                .examples
+                .iter()
+                .flat_map(|example| prepare(example))
+                .collect(),
+            custom,
+        }
+    }
+}
+
+/// Currently registered custom symbols.
+static REGISTRY: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+
+/// All strings and symbols leaked for custom symbols.
+static STRINGS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+static SYMBOLS: Mutex<Vec<&'static Symbol>> = Mutex::new(Vec::new());
+
+/// Returns a static copy of `string`.
+///
+/// Symbols are referenced by `&'static` everywhere, so custom symbols have to be leaked.
+/// Strings are only leaked once, so editing custom symbols does not leak memory repeatedly.
+pub(super) fn intern(string: &str) -> &'static str {
+    let mut strings = STRINGS.lock().unwrap();
+    match strings.iter().find(|&&other| other == string) {
+        Some(string) => string,
+        None => {
+            let string = Box::leak(string.to_owned().into_boxed_str());
+            strings.push(string);
+            string
+        }
+    }
+}
+
+/// Returns a static copy of `symbol`, which is only leaked once.
+fn intern_symbol(symbol: Symbol) -> &'static Symbol {
+    let mut symbols = SYMBOLS.lock().unwrap();
+    match symbols.iter().find(|&&other| *other == symbol) {
+        Some(symbol) => symbol,
+        None => {
+            let symbol = Box::leak(Box::new(symbol));
+            symbols.push(symbol);
+            symbol
+        }
+    }
+}
+
+/// Replaces the registered custom symbols, so they are found by [`Symbol::from_id`],
+/// searching and [`match_custom_symbols`].
+///
+/// Symbols with the same command and package as an earlier one are ignored.
+pub fn set_custom_symbols(symbols: Vec<CustomSymbol>) {
+    let entries = symbols
+        .into_iter()
+        .map(Entry::new)
+        .unique_by(|entry| entry.id)
+        .collect();
+    *REGISTRY.write().unwrap() = entries;
+}
+
+/// Returns the registered custom symbols.
+pub fn custom_symbols() -> Vec<CustomSymbol> {
+    let registry = REGISTRY.read().unwrap();
+    registry.iter().map(|entry| entry.custom.clone()).collect()
+}
+
+/// Returns the ids and symbols of all registered custom symbols.
+pub(super) fn symbols() -> Vec<(&'static str, &'static Symbol)> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .iter()
+        .map(|entry| (entry.id, entry.symbol))
+        .collect()
+}
+
+/// Returns the registered custom symbol with the given `id`.
+pub(super) fn get(id: &str) -> Option<&'static Symbol> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| entry.symbol)
+}
+
+/// Returns the ids of the custom symbols with an example close to the `strokes`,
+/// together with their distance, closest first.
+pub fn match_custom_symbols(strokes: &[Stroke]) -> Vec<(&'static str, f64)> {
+    matches(&REGISTRY.read().unwrap(), strokes)
+}
+
+/// Inserts the matched custom symbols into the results of the model.
+///
+/// The closer a custom symbol matches, the higher it is ranked, but weak matches are still
+/// ranked among the top results.
+pub fn insert_custom_matches(ids: &mut Vec<&'static str>, matches: &[(&'static str, f64)]) {
+    for &(id, distance) in matches.iter().rev() {
+        let rank = (distance / MAX_DISTANCE * MATCH_DEPTH as f64) as usize;
+        ids.insert(rank.min(MATCH_DEPTH).min(ids.len()), id);
+    }
+}
+
+/// Returns the entries with an example close to the `strokes`, see [`match_custom_symbols`].
+fn matches(entries: &[Entry], strokes: &[Stroke]) -> Vec<(&'static str, f64)> {
+    let Some(drawing) = normalize(strokes) else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let distance = entry
+                .examples
+                .iter()
+                .map(|example| distance(&drawing, example))
+                .min_by(f64::total_cmp)?;
+            (distance <= MAX_DISTANCE).then_some((entry.id, distance))
+        })
+        .sorted_by(|(_, a), (_, b)| a.total_cmp(b))
+        .collect()
+}
+
+/// Normalizes the example and distorted copies of it, see [`AUGMENTATIONS`].
+fn prepare(example: &[Stroke]) -> Vec<Vec<Point>> {
+    let copies = (0..AUGMENTATIONS).map(|seed| {
+        let mut copy = example.to_vec();
+        copy.rotate(0.1, seed);
+        copy.scale_jitter(0.1, seed);
+        copy
+    });
+    std::iter::once(example.to_vec())
+        .chain(copies)
+        .filter_map(|strokes| normalize(&strokes))
+        .collect()
+}
+
+/// Converts the strokes into [`MATCH_POINTS`] points centered around the origin,
+/// whose larger side has a length of 1.
+///
+/// Points are distributed by the length of the strokes, but each stroke keeps at least two.
+/// Returns `None` if there are no points.
+fn normalize(strokes: &[Stroke]) -> Option<Vec<Point>> {
+    let strokes = strokes
+        .iter()
+        .filter(|stroke| !stroke.is_empty())
+        .collect_vec();
+    let bounds = strokes
+        .iter()
+        .map(|stroke| stroke.bounding_box())
+        .reduce(|mut bounds, bb| {
+            bounds.encompass_point(&bb.lower_left);
+            bounds.encompass_point(&bb.upper_right);
+            bounds
+        })?;
+    let center = (bounds.lower_left + bounds.upper_right) * 0.5;
+    let size = bounds.width().max(bounds.height());
+    let total_length: f64 = strokes.iter().map(|stroke| stroke.length()).sum();
+
+    let mut points = Vec::with_capacity(MATCH_POINTS);
+    for &stroke in &strokes {
+        let share = if total_length == 0.0 {
+            1.0 / strokes.len() as f64
+        } else {
+            stroke.length() / total_length
+        };
+        let mut stroke = stroke.clone();
+        stroke.redistribute(((share * MATCH_POINTS as f64).round() as usize).max(2));
+        points.extend(stroke.points().map(|&point| {
+            if size == 0.0 {
+                ZERO_POINT
+            } else {
+                (point - center) * size.recip()
+            }
+        }));
+    }
+    Some(points)
+}
+
+/// Computes the mean distance of the points to the closest point of the other drawing,
+/// in both directions.
+///
+/// The order of the points does not matter, so drawings with a different stroke order or
+/// direction still match.
+fn distance(a: &[Point], b: &[Point]) -> f64 {
+    let directed = |a: &[Point], b: &[Point]| {
+        a.iter()
+            .map(|p| {
+                b.iter()
+                    .map(|q| p.euclidean_distance(q))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum::<f64>()
+            / a.len() as f64
+    };
+    (directed(a, b) + directed(b, a)) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::search;
+
+    /// Returns a circle around `(x, y)`, starting at the angle `start`.
+    fn circle(x: f64, y: f64, radius: f64, start: f64) -> Stroke {
+        Stroke::new(
+            (0..=40)
+                .map(|i| {
+                    let angle = start + i as f64 / 40.0 * std::f64::consts::TAU;
+                    Point {
+                        x: x + radius * angle.cos(),
+                        y: y + radius * angle.sin(),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn line(from: (f64, f64), to: (f64, f64)) -> Stroke {
+        Stroke::new(vec![
+            Point {
+                x: from.0,
+                y: from.1,
+            },
+            Point { x: to.0, y: to.1 },
+        ])
+    }
+
+    /// A circle with a vertical line through it, similar to `\phi`.
+    fn phi() -> Vec<Stroke> {
+        vec![
+            circle(50.0, 50.0, 20.0, 0.0),
+            line((50.0, 20.0), (50.0, 80.0)),
+        ]
+    }
+
+    fn custom(command: &str, examples: Vec<Vec<Stroke>>) -> CustomSymbol {
+        CustomSymbol {
+            command: command.to_owned(),
+            package: None,
+            text_mode: false,
+            math_mode: true,
+            examples,
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let symbols = vec![
+            custom("\\R", vec![phi()]),
+            CustomSymbol {
+                package: Some("stmaryrd".to_owned()),
+                ..custom("\\llbracket", Vec::new())
+            },
+        ];
+        let json = CustomSymbol::list_to_json(&symbols);
+        assert_eq!(
+            CustomSymbol::list_from_json(json.as_bytes()).unwrap(),
+            symbols
+        );
+
+        let newer = json.replace("\"version\": 1", "\"version\": 2");
+        assert!(CustomSymbol::list_from_json(newer.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_id() {
+        let symbol = custom("\\R", Vec::new());
+        assert_eq!(symbol.id(), "latex2e-custom-R");
+        assert_eq!(symbol.id(), symbol.symbol().id());
+        let latex2e = CustomSymbol {
+            package: Some("latex2e".to_owned()),
+            ..symbol.clone()
+        };
+        assert_eq!(latex2e.id(), symbol.id());
+        let stmaryrd = CustomSymbol {
+            package: Some("stmaryrd".to_owned()),
+            ..symbol.clone()
+        };
+        assert_ne!(stmaryrd.id(), symbol.id());
+    }
+
+    #[test]
+    fn test_match() {
+        let entries = [
+            Entry::new(custom("\\testphi", vec![phi()])),
+            Entry::new(custom(
+                "\\testcircle",
+                vec![vec![circle(0.0, 0.0, 1.0, 0.0)]],
+            )),
+        ];
+
+        // different size, position, stroke order and starting point
+        let drawing = vec![
+            line((210.0, 300.0), (210.0, 100.0)),
+            circle(210.0, 200.0, 70.0, 1.0),
+        ];
+        let matches = matches(&entries, &drawing);
+        assert_eq!(matches[0].0, entries[0].id);
+        assert!(matches[0].1 < 0.02);
+
+        let matches = self::matches(&entries, &[line((0.0, 0.0), (100.0, 0.0))]);
+        assert!(matches.is_empty());
+        assert!(self::matches(&entries, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_match_distorted() {
+        let entries = [Entry::new(custom("\\testphi", vec![phi()]))];
+        let mut drawing = phi();
+        drawing.rotate(0.1, 7);
+        drawing.noise(0.01, 7);
+        assert_eq!(matches(&entries, &drawing).len(), 1);
+    }
+
+    #[test]
+    fn test_insert_custom_matches() {
+        let mut ids = vec!["a", "b", "c", "d", "e", "f", "g"];
+        insert_custom_matches(&mut ids, &[("x", 0.0), ("y", MAX_DISTANCE / 2.0)]);
+        assert_eq!(ids, vec!["x", "a", "b", "y", "c", "d", "e", "f", "g"]);
+
+        let mut ids = vec!["a"];
+        insert_custom_matches(&mut ids, &[("x", MAX_DISTANCE)]);
+        assert_eq!(ids, vec!["a", "x"]);
+    }
+
+    #[test]
+    fn test_registry() {
+        let symbols = vec![
+            custom("\\hieroglyphictestnorm", vec![phi()]),
+            CustomSymbol {
+                package: Some("hieroglyphictest".to_owned()),
+                ..custom("\\hieroglyphictestnorm", Vec::new())
+            },
+            // duplicates are ignored
+            custom("\\hieroglyphictestnorm", Vec::new()),
+        ];
+        set_custom_symbols(symbols.clone());
+        assert_eq!(custom_symbols(), symbols[..2]);
+
+        let ids = search("hieroglyphictestnorm", str::to_owned);
+        assert_eq!(ids.len(), 2);
+        for id in ids {
+            let symbol = Symbol::from_id(id).unwrap();
+            assert_eq!(symbol.id(), id);
+            assert!(symbol.is_custom());
+            assert!(symbol.is_recognizable());
+            assert_eq!(symbol.table, TABLE);
+        }
+        assert_eq!(match_custom_symbols(&phi()).len(), 1);
+
+        set_custom_symbols(Vec::new());
+        assert!(search("hieroglyphictestnorm", str::to_owned).is_empty());
+        assert!(match_custom_symbols(&phi()).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Stroke, Symbol};
+
+/// Position of the x-height guide, relative to the height of the canvas.
+pub const X_HEIGHT: f64 = 0.4;
+/// Position of the baseline guide, relative to the height of the canvas.
+pub const BASELINE: f64 = 0.65;
+
+/// Number of top results that are considered for re-ranking.
+const RERANK_DEPTH: usize = 5;
+
+/// Expected vertical position of symbols, which mainly differ in their position,
+/// e.g. `\cdot` and `.`, or `\_` and `-`.
+///
+/// The position is relative to the guides, see [`vertical_position`].
+const POSITIONS: &[(&str, f64)] = &[
+    // below the baseline
+    ("\\textunderscore", -0.2),
+    ("\\mathunderscore", -0.2),
+    // on the baseline
+    ("\\ldotp", 0.0),
+    ("\\ldots", 0.0),
+    // on the math axis, halfway between the baseline and the x-height
+    ("\\cdotp", 0.5),
+    ("\\cdot", 0.5),
+    ("\\cdots", 0.5),
+    ("\\textperiodcentered", 0.5),
+    ("\\textendash", 0.5),
+    ("\\textemdash", 0.5),
+];
+
+/// Computes the vertical position of the strokes relative to the guides of a canvas
+/// with the given `height`.
+///
+/// The position is `0` for a drawing centered on the baseline and `1` for one centered on the
+/// x-height. Returns `None` if there are no points.
+pub fn vertical_position(strokes: &[Stroke], height: f64) -> Option<f64> {
+    let mut points = strokes.iter().flat_map(|stroke| stroke.points());
+    let first = points.next()?;
+    let (top, bottom) = points.fold((first.y, first.y), |(top, bottom), point| {
+        (top.min(point.y), bottom.max(point.y))
+    });
+
+    let center = (top + bottom) / 2.0;
+    let baseline = BASELINE * height;
+    let x_height = X_HEIGHT * height;
+    Some((baseline - center) / (baseline - x_height))
+}
+
+/// Re-ranks the top results based on the `position` of the drawing relative to the guides.
+///
+/// Only symbols with a known position swap places with each other, ordered by how close their
+/// expected position is to the drawn one. All other results keep their rank.
+pub fn rerank_by_position(ids: &mut [&'static str], position: f64) {
+    let depth = ids.len().min(RERANK_DEPTH);
+    let (slots, mut candidates): (Vec<_>, Vec<_>) = ids[..depth]
+        .iter()
+        .enumerate()
+        .filter_map(|(slot, &id)| Some((slot, (id, expected_position(id)?))))
+        .unzip();
+
+    candidates.sort_by(|(_, a), (_, b)| (a - position).abs().total_cmp(&(b - position).abs()));
+    for (slot, (id, _)) in slots.into_iter().zip(candidates) {
+        ids[slot] = id;
+    }
+}
+
+/// Returns the expected position of the symbol with the given `id`, if known.
+fn expected_position(id: &str) -> Option<f64> {
+    let symbol = Symbol::from_id(id)?;
+    POSITIONS
+        .iter()
+        .find(|(command, _)| *command == symbol.command)
+        .map(|&(_, position)| position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::Point;
+
+    /// Returns the id of the `latex2e` symbol with the given command.
+    fn id(command: &str) -> &'static str {
+        Symbol::from_id(&crate::classify::id::id("latex2e", "OT1", command))
+            .unwrap()
+            .id()
+    }
+
+    fn dot(y: f64) -> Vec<Stroke> {
+        vec![Stroke::new(vec![Point { x: 50.0, y }])]
+    }
+
+    #[test]
+    fn test_position() {
+        let position = |strokes: &[Stroke]| vertical_position(strokes, 100.0).unwrap();
+        assert_eq!(vertical_position(&[], 100.0), None);
+        assert!(position(&dot(65.0)).abs() < 1e-9);
+        assert!((position(&dot(40.0)) - 1.0).abs() < 1e-9);
+
+        let line = vec![Stroke::new(vec![
+            Point { x: 0.0, y: 50.0 },
+            Point { x: 0.0, y: 55.0 },
+        ])];
+        assert!((position(&line) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rerank() {
+        let (cdot, ldotp) = (id("\\cdot"), id("\\ldotp"));
+        let other = id("\\alpha");
+
+        let mut ids = [cdot, other, ldotp];
+        rerank_by_position(&mut ids, 0.1);
+        assert_eq!(ids, [ldotp, other, cdot]);
+
+        rerank_by_position(&mut ids, 0.6);
+        assert_eq!(ids, [cdot, other, ldotp]);
+    }
+
+    #[test]
+    fn test_rerank_only_top_results() {
+        let (cdot, ldotp) = (id("\\cdot"), id("\\ldotp"));
+        let other = id("\\alpha");
+
+        let mut ids = [cdot, other, other, other, other, ldotp];
+        rerank_by_position(&mut ids, 0.0);
+        assert_eq!(ids, [cdot, other, other, other, other, ldotp]);
+    }
+}
@@ -0,0 +1,145 @@
+//! Ids of symbols, which are shared with the build script and `tools/render-icons`,
+//! so this module must not depend on the rest of the crate.
+
+use base64::Engine;
+
+/// Returns the id of the symbol with the `command` from the `package` and `font_encoding`,
+/// e.g. `latex2e-alpha` for `\alpha`, `amssymb-mathbb-R` for `\mathbb{R}` or
+/// `vntex-t5-OHORN` for `\OHORN`.
+///
+/// The font encoding is omitted if it is the default OT1 encoding. Ids only consist of ASCII
+/// letters, digits and `-`, so they can be used in icon names and URLs.
+///
+/// Ids must never change for existing symbols, since they are used to store them.
+pub fn id(package: &str, font_encoding: &str, command: &str) -> String {
+    let mut parts = vec![package.to_owned()];
+    if font_encoding != "OT1" {
+        parts.push(font_encoding.to_lowercase());
+    }
+    parts.push(slug(command));
+    parts.join("-")
+}
+
+/// Returns the id which was used before the ids of [`id`], i.e. the base64 encoding of
+/// `package-fontenc-command` with backslashes replaced by `_`.
+///
+/// The training data of the model is still labelled with these ids.
+pub fn legacy_id(package: &str, font_encoding: &str, command: &str) -> String {
+    let id = format!(
+        "{}-{}-{}",
+        package,
+        font_encoding,
+        command.replace('\\', "_")
+    );
+    base64::prelude::BASE64_STANDARD.encode(id)
+}
+
+/// Turns the command into words separated by `-`, e.g. `\mathcal{A}` into `mathcal-A`.
+///
+/// Other characters are spelled out, e.g. `\{` becomes `lbrace`. Commands which are not
+/// control sequences, like `[`, are prefixed with `char`.
+fn slug(command: &str) -> String {
+    let (mut words, name) = match command.strip_prefix('\\') {
+        Some(name) => (Vec::new(), name),
+        None => (vec!["char".to_owned()], command),
+    };
+    // backslashes and braces only separate the words of commands like `\not\approx`,
+    // unless they are the command itself
+    let separate = name.chars().any(|c| c.is_ascii_alphanumeric());
+
+    let mut word = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if !(separate && matches!(c, '\\' | '{' | '}')) {
+            words.push(spell(c));
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words.join("-")
+}
+
+/// Returns the name of a character which cannot be part of an id.
+fn spell(c: char) -> String {
+    let name = match c {
+        '!' => "exclamation",
+        '"' => "quote",
+        '#' => "hash",
+        '$' => "dollar",
+        '%' => "percent",
+        '&' => "ampersand",
+        '\'' => "apostrophe",
+        '(' => "lparen",
+        ')' => "rparen",
+        '*' => "asterisk",
+        '+' => "plus",
+        ',' => "comma",
+        '-' => "dash",
+        '.' => "period",
+        '/' => "slash",
+        ':' => "colon",
+        ';' => "semicolon",
+        '<' => "less",
+        '=' => "equals",
+        '>' => "greater",
+        '?' => "question",
+        '@' => "at",
+        '[' => "lbracket",
+        '\\' => "backslash",
+        ']' => "rbracket",
+        '^' => "caret",
+        '_' => "underscore",
+        '`' => "grave",
+        '{' => "lbrace",
+        '|' => "vert",
+        '}' => "rbrace",
+        '~' => "tilde",
+        ' ' => "space",
+        c => return format!("u{:04x}", c as u32),
+    };
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id() {
+        assert_eq!(id("latex2e", "OT1", "\\alpha"), "latex2e-alpha");
+        assert_eq!(id("amssymb", "OT1", "\\mathbb{R}"), "amssymb-mathbb-R");
+        assert_eq!(id("vntex", "T5", "\\OHORN"), "vntex-t5-OHORN");
+        assert_eq!(id("latex2e", "T4", "\\B{D}"), "latex2e-t4-B-D");
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("\\not\\approx"), "not-approx");
+        assert_eq!(slug("\\sqrt{}"), "sqrt");
+        assert_eq!(slug("\\{"), "lbrace");
+        assert_eq!(slug("\\|"), "vert");
+        assert_eq!(slug("|"), "char-vert");
+        assert_eq!(slug("!`"), "char-exclamation-grave");
+        assert_eq!(slug("\\---"), "dash-dash-dash");
+        assert_eq!(slug("\\ä"), "u00e4");
+    }
+
+    #[test]
+    fn test_legacy_id() {
+        assert_eq!(
+            legacy_id("latex2e", "OT1", "\\alpha"),
+            "bGF0ZXgyZS1PVDEtX2FscGhh"
+        );
+        assert_eq!(
+            legacy_id("latex2e", "OT1", "\\textasciicircum"),
+            "bGF0ZXgyZS1PVDEtX3RleHRhc2NpaWNpcmN1bQ=="
+        );
+    }
+}
@@ -0,0 +1,321 @@
+/// A binary raster image of a symbol, e.g. from a screenshot.
+///
+/// Pixels are either foreground (ink) or background.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Image {
+    /// Create a new image from non-premultiplied RGBA data with the given `stride`.
+    ///
+    /// The image is binarized using [Otsu's method](https://en.wikipedia.org/wiki/Otsu%27s_method),
+    /// transparent pixels are treated as white. Since symbols consist of thin lines,
+    /// the less common of the two classes is used as foreground, so both dark-on-light
+    /// and light-on-dark images are supported.
+    pub fn from_rgba(width: usize, height: usize, stride: usize, data: &[u8]) -> Self {
+        let luma = (0..height)
+            .flat_map(|y| (0..width).map(move |x| y * stride + x * 4))
+            .map(|i| {
+                let [r, g, b, a] = [data[i], data[i + 1], data[i + 2], data[i + 3]].map(f64::from);
+                let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+                // composite over a white background
+                let alpha = a / 255.0;
+                (luma * alpha + 255.0 * (1.0 - alpha)).round() as u8
+            })
+            .collect::<Vec<_>>();
+
+        let threshold = otsu_threshold(&luma);
+        let mut pixels: Vec<bool> = luma.iter().map(|&l| l <= threshold).collect();
+        let foreground = pixels.iter().filter(|&&p| p).count();
+        if foreground * 2 > pixels.len() {
+            pixels.iter_mut().for_each(|p| *p = !*p);
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Returns true if the image does not contain any foreground.
+    pub fn is_empty(&self) -> bool {
+        !self.pixels.contains(&true)
+    }
+
+    /// Returns whether the pixel at `(x, y)` is foreground.
+    ///
+    /// Pixels outside of the image are always background.
+    pub(super) fn get(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return false;
+        }
+        self.pixels[y as usize * self.width + x as usize]
+    }
+
+    fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.pixels[y * self.width + x] = value;
+    }
+
+    /// Returns an iterator over the coordinates of all foreground pixels.
+    pub(super) fn foreground(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.pixels[y * self.width + x])
+    }
+
+    /// Prepares the image as input for the classifier.
+    ///
+    /// The image is cropped to its content, resized to fit into a `size`×`size` image
+    /// whilst keeping the aspect-ratio, and the lines are thinned to a width of a single pixel,
+    /// similar to how the strokes are drawn.
+    ///
+    /// Returns `None` if the image does not have any foreground.
+    pub(super) fn prepare(&self, size: usize) -> Option<Image> {
+        let mut image = self.crop()?.fit(size);
+        image.thin();
+        Some(image)
+    }
+
+    /// Crops the image to the bounding box of the foreground.
+    fn crop(&self) -> Option<Image> {
+        let (mut min_x, mut min_y) = self.foreground().next()?;
+        let (mut max_x, mut max_y) = (min_x, min_y);
+        for (x, y) in self.foreground() {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+        let pixels = (min_y..=max_y)
+            .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Some(Image {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Resizes the image to a `size`×`size` image, keeping the aspect-ratio and centering it.
+    ///
+    /// Pixels are mapped in both directions, so thin lines do not vanish when shrinking
+    /// and no gaps appear when enlarging.
+    fn fit(&self, size: usize) -> Image {
+        let mut target = Image {
+            width: size,
+            height: size,
+            pixels: vec![false; size * size],
+        };
+
+        let max = (size - 1) as f64;
+        let longest_side = self.width.max(self.height) - 1;
+        let scale = if longest_side == 0 {
+            0.0
+        } else {
+            max / longest_side as f64
+        };
+        let offset_x = (max - (self.width - 1) as f64 * scale) / 2.0;
+        let offset_y = (max - (self.height - 1) as f64 * scale) / 2.0;
+
+        // source to target
+        for (x, y) in self.foreground() {
+            let tx = (x as f64 * scale + offset_x).round() as usize;
+            let ty = (y as f64 * scale + offset_y).round() as usize;
+            target.set(tx.min(size - 1), ty.min(size - 1), true);
+        }
+
+        // target to source
+        if scale > 0.0 {
+            for ty in 0..size {
+                for tx in 0..size {
+                    let x = ((tx as f64 - offset_x) / scale).round() as isize;
+                    let y = ((ty as f64 - offset_y) / scale).round() as isize;
+                    if self.get(x, y) {
+                        target.set(tx, ty, true);
+                    }
+                }
+            }
+        }
+
+        target
+    }
+
+    /// Thins the foreground to lines with a width of a single pixel,
+    /// using the [Zhang-Suen thinning algorithm](https://doi.org/10.1145/357994.358023).
+    fn thin(&mut self) {
+        loop {
+            let mut changed = false;
+            for step in 0..2 {
+                let remove = self
+                    .foreground()
+                    .filter(|&(x, y)| {
+                        let (x, y) = (x as isize, y as isize);
+                        // neighbours in clockwise order, starting at the top
+                        let n = [
+                            self.get(x, y - 1),
+                            self.get(x + 1, y - 1),
+                            self.get(x + 1, y),
+                            self.get(x + 1, y + 1),
+                            self.get(x, y + 1),
+                            self.get(x - 1, y + 1),
+                            self.get(x - 1, y),
+                            self.get(x - 1, y - 1),
+                        ];
+                        let count = n.iter().filter(|&&p| p).count();
+                        let transitions = (0..8).filter(|&i| !n[i] && n[(i + 1) % 8]).count();
+                        let (north, east, south, west) = (n[0], n[2], n[4], n[6]);
+                        let erode = if step == 0 {
+                            !(east && south && (north || west))
+                        } else {
+                            !(north && west && (east || south))
+                        };
+                        (2..=6).contains(&count) && transitions == 1 && erode
+                    })
+                    .collect::<Vec<_>>();
+
+                changed |= !remove.is_empty();
+                for (x, y) in remove {
+                    self.set(x, y, false);
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+}
+
+/// Computes the threshold that best separates the values into two classes,
+/// using [Otsu's method](https://en.wikipedia.org/wiki/Otsu%27s_method).
+fn otsu_threshold(values: &[u8]) -> u8 {
+    let mut histogram = [0usize; 256];
+    for &value in values {
+        histogram[value as usize] += 1;
+    }
+
+    let total = values.len() as f64;
+    let sum: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(value, &count)| value as f64 * count as f64)
+        .sum();
+
+    let mut best = (0, 0.0);
+    let mut background_weight = 0.0;
+    let mut background_sum = 0.0;
+    for (value, &count) in histogram.iter().enumerate() {
+        background_weight += count as f64;
+        background_sum += value as f64 * count as f64;
+        let foreground_weight = total - background_weight;
+        if background_weight == 0.0 || foreground_weight == 0.0 {
+            continue;
+        }
+
+        let background_mean = background_sum / background_weight;
+        let foreground_mean = (sum - background_sum) / foreground_weight;
+        let variance =
+            background_weight * foreground_weight * (background_mean - foreground_mean).powi(2);
+        if variance > best.1 {
+            best = (value, variance);
+        }
+    }
+    best.0 as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an RGBA image from the given rows, where `#` is black and everything else white.
+    fn rgba(rows: &[&str]) -> Image {
+        let width = rows[0].len();
+        let data = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .flat_map(|c| {
+                if c == '#' {
+                    [0, 0, 0, 255]
+                } else {
+                    [255, 255, 255, 255]
+                }
+            })
+            .collect::<Vec<u8>>();
+        Image::from_rgba(width, rows.len(), width * 4, &data)
+    }
+
+    /// Creates an image from the given rows, where `#` is foreground.
+    fn bitmap(rows: &[&str]) -> Image {
+        Image {
+            width: rows[0].len(),
+            height: rows.len(),
+            pixels: rows
+                .iter()
+                .flat_map(|row| row.chars())
+                .map(|c| c == '#')
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_binarize() {
+        let image = rgba(&["....", ".##.", "...."]);
+        assert_eq!(image.foreground().collect::<Vec<_>>(), vec![(1, 1), (2, 1)]);
+
+        // light on dark
+        let image = rgba(&["####", "#..#", "####"]);
+        assert_eq!(image.foreground().collect::<Vec<_>>(), vec![(1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_transparent_is_background() {
+        let data = [0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0];
+        let image = Image::from_rgba(3, 1, 12, &data);
+        assert_eq!(image.foreground().collect::<Vec<_>>(), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let image = rgba(&["...", "..."]);
+        assert!(image.is_empty());
+        assert_eq!(image.prepare(32), None);
+    }
+
+    #[test]
+    fn test_crop() {
+        let image = bitmap(&[".....", "..#..", "..##.", "....."]);
+        assert_eq!(image.crop(), Some(bitmap(&["#.", "##"])));
+    }
+
+    #[test]
+    fn test_fit() {
+        // a horizontal line spans the whole width and is centered vertically
+        let image = bitmap(&["####"]).fit(32);
+        assert!((0..32).all(|x| image.get(x, 16)));
+        assert!(image.foreground().all(|(_, y)| y.abs_diff(16) <= 6));
+
+        // a single pixel is centered
+        let image = bitmap(&["#"]).fit(32);
+        assert_eq!(image.foreground().collect::<Vec<_>>(), vec![(16, 16)]);
+    }
+
+    #[test]
+    fn test_thin() {
+        // a thick vertical bar becomes a single line
+        let rows = vec!["..#####.."; 32];
+        let image = bitmap(&rows).prepare(32).unwrap();
+        for y in 0..32 {
+            let width = (0..32).filter(|&x| image.get(x, y)).count();
+            assert!(width <= 1, "row {} has a width of {}", y, width);
+        }
+        assert!(image.foreground().count() > 16);
+    }
+}
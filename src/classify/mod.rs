@@ -1,11 +1,32 @@
+// mostly used for generating training data and in tests, the app only distorts the examples
+// of custom symbols
+#[allow(dead_code)]
+mod augment;
 mod classifier;
+mod confusion;
+mod custom;
+mod guides;
+mod id;
+mod image;
 mod point;
+mod recording;
 mod rect;
+mod search;
+mod segment;
 mod stroke;
 mod symbol;
 
-pub use self::classifier::Classifier;
-pub use self::point::Point;
+pub use self::classifier::{is_drawn, Classifier, ClassifyError, MAX_STROKES};
+pub use self::confusion::confusables;
+pub use self::custom::{
+    custom_symbols, insert_custom_matches, match_custom_symbols, set_custom_symbols, CustomSymbol,
+};
+pub use self::guides::{rerank_by_position, vertical_position, BASELINE, X_HEIGHT};
+pub use self::image::Image;
+pub use self::point::{Point, PointDetails};
+pub use self::recording::Recording;
+pub use self::search::search;
+pub use self::segment::{segment, transcribe};
 pub use self::stroke::Stroke;
-pub use self::symbol::Symbol;
 pub use self::symbol::SYMBOL_COUNT;
+pub use self::symbol::{dedup_aliases, symbols_by_package, symbols_by_table, Symbol};
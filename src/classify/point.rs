@@ -1,6 +1,6 @@
 use std::ops::{Add, Mul, Sub};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 // Original code from:
 // https://github.com/FineFindus/detexify-rust/blob/311002feb0519f483ef1f9cc8206648286128ff5/src/point.rs
@@ -14,7 +14,7 @@ pub(super) const ONE_POINT: Point = Point { x: 1.0, y: 1.0 };
 const DELTA: f64 = 1e-10;
 
 /// A simple point, consisting of a (x, y) coordinate.
-#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Point {
     /// The x-coordinate of the point.
     pub x: f64,
@@ -22,6 +22,20 @@ pub struct Point {
     pub y: f64,
 }
 
+/// Additional information about how a [`Point`] was drawn.
+///
+/// Only available for points drawn on the canvas, e.g. with a stylus.
+/// It is never used for classification or uploaded.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PointDetails {
+    /// Time in milliseconds since the start of the drawing.
+    pub time: u64,
+    /// Pressure in `[0, 1]`, if supported by the input device.
+    pub pressure: Option<f64>,
+    /// Tilt in x and y direction in `[-1, 1]`, if supported by the input device.
+    pub tilt: Option<(f64, f64)>,
+}
+
 impl Point {
     /// Computes the dot product between self and another point.
     pub(super) fn dot(&self, p: &Point) -> f64 {
@@ -51,12 +65,30 @@ impl Point {
         self.euclidean_distance(&p) < DELTA
     }
 
-    /// Calculates the angle (in radians) between two vectors formed by three [`Point`]s:
+    /// Calculates the turning angle (in radians) at `p` of the path formed by three [`Point`]s:
     /// `self` (the origin point), `p` (an intermediate point), and `q` (the endpoint).
+    ///
+    /// The angle is between `0` (continuing straight) and `π` (reversing the direction).
+    /// If two of the points are identical, the angle is `0`.
     pub(super) fn angle(&self, p: Point, q: Point) -> f64 {
         let v = p - *self;
         let w = q - p;
 
+        let norm = v.norm() * w.norm();
+        if norm == 0.0 {
+            return 0.0;
+        }
+        (v.dot(&w) / norm).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Previous, incorrect computation of [`Point::angle`], which divides by the arccos
+    /// of the norm product, instead of taking the arccos of the cosine.
+    ///
+    /// Only kept for preprocessing input for models trained with it.
+    pub(super) fn legacy_angle(&self, p: Point, q: Point) -> f64 {
+        let v = p - *self;
+        let w = q - p;
+
         v.dot(&w) / (v.norm() * w.norm()).clamp(-1.0, 1.0).acos()
     }
 }
@@ -96,7 +128,10 @@ impl Mul<f64> for Point {
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
     use super::*;
+    use crate::classify::augment::SeededRng;
 
     static SMALL_DELTA: f64 = 1e-11;
 
@@ -121,6 +156,53 @@ mod tests {
         assert_eq!(Point { x: 1.0, y: 3.0 } * 4.0, Point { x: 4.0, y: 12.0 })
     }
 
+    #[test]
+    fn test_angle() {
+        let origin = ZERO_POINT;
+        let p = Point { x: 1.0, y: 0.0 };
+        let cases = [
+            (Point { x: 2.0, y: 0.0 }, 0.0),
+            (Point { x: 2.0, y: 1.0 }, FRAC_PI_4),
+            (Point { x: 1.0, y: 1.0 }, FRAC_PI_2),
+            (Point { x: 1.0, y: -1.0 }, FRAC_PI_2),
+            (Point { x: 0.0, y: 1.0 }, 3.0 * FRAC_PI_4),
+            (Point { x: 0.0, y: 0.0 }, PI),
+            // degenerate case
+            (p, 0.0),
+        ];
+        for (q, expected) in cases {
+            let angle = origin.angle(p, q);
+            assert!(
+                (angle - expected).abs() < 1e-10,
+                "angle to {:?} was {}, expected {}",
+                q,
+                angle,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_angle_properties() {
+        let mut rng = SeededRng::new(32);
+        let mut random_point = || Point {
+            x: rng.symmetric(100.0),
+            y: rng.symmetric(100.0),
+        };
+
+        for _ in 0..1000 {
+            let (a, b, c) = (random_point(), random_point(), random_point());
+            let angle = a.angle(b, c);
+            assert!((0.0..=PI).contains(&angle));
+            // reversing the path does not change the turning angle
+            assert!((angle - c.angle(b, a)).abs() < 1e-9);
+            // scaling and translating does not change the angle
+            let offset = random_point();
+            let transform = |p: Point| p * 3.5 + offset;
+            assert!((angle - transform(a).angle(transform(b), transform(c))).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_approx_eq_vec() {
         assert!(Point { x: 1.0, y: 3.0 }.approx_eq(Point {
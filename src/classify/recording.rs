@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Point, PointDetails, Stroke};
+
+/// Version of the recording file format written by this version of the app.
+const VERSION: u32 = 1;
+
+/// A recorded drawing, including the timing and stylus data of each point.
+///
+/// Recordings can be saved to a file and replayed later on,
+/// e.g. to reproduce a classification from a bug report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// Version of the file format.
+    pub version: u32,
+    /// Width of the canvas the drawing was recorded on.
+    pub width: f64,
+    /// Height of the canvas the drawing was recorded on.
+    pub height: f64,
+    /// Recorded strokes, in the order they were drawn.
+    pub strokes: Vec<Vec<TimedPoint>>,
+}
+
+/// A [`Point`] with the time it was recorded at and optional stylus data.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimedPoint {
+    /// The x-coordinate of the point.
+    pub x: f64,
+    /// The y-coordinate of the point.
+    pub y: f64,
+    /// Time in milliseconds since the start of the drawing.
+    pub time: u64,
+    /// Pressure in `[0, 1]`, if recorded with a stylus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<f64>,
+    /// Tilt in x and y direction in `[-1, 1]`, if recorded with a stylus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tilt: Option<(f64, f64)>,
+}
+
+impl TimedPoint {
+    fn new(Point { x, y }: Point, details: PointDetails) -> Self {
+        Self {
+            x,
+            y,
+            time: details.time,
+            pressure: details.pressure,
+            tilt: details.tilt,
+        }
+    }
+
+    fn details(&self) -> PointDetails {
+        PointDetails {
+            time: self.time,
+            pressure: self.pressure,
+            tilt: self.tilt,
+        }
+    }
+}
+
+impl Recording {
+    /// Create a new recording of the given strokes, drawn on a canvas with the given size.
+    ///
+    /// The timing and stylus data is taken from the [`PointDetails`] of the strokes.
+    /// Strokes without details use the time of the previous point.
+    pub fn new(width: f64, height: f64, strokes: &[Stroke]) -> Self {
+        let mut time = 0;
+        let strokes = strokes
+            .iter()
+            .map(|stroke| {
+                let mut details = stroke.details();
+                stroke
+                    .points()
+                    .map(|&point| {
+                        let details = details.next().copied().unwrap_or(PointDetails {
+                            time,
+                            ..Default::default()
+                        });
+                        time = details.time;
+                        TimedPoint::new(point, details)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            version: VERSION,
+            width,
+            height,
+            strokes,
+        }
+    }
+
+    /// Returns the recorded strokes, including their timing and stylus data.
+    pub fn strokes(&self) -> Vec<Stroke> {
+        self.transformed_strokes(|point| point)
+    }
+
+    /// Returns the recorded strokes scaled to a canvas with the given size.
+    ///
+    /// The drawing keeps its aspect ratio and is centered on the canvas,
+    /// so it is classified the same as on the canvas it was recorded on.
+    /// The strokes are not scaled if either canvas has no valid size.
+    pub fn strokes_on_canvas(&self, width: f64, height: f64) -> Vec<Stroke> {
+        let scale = (width / self.width).min(height / self.height);
+        if !scale.is_finite() || scale <= 0.0 {
+            return self.strokes();
+        }
+        let offset_x = (width - self.width * scale) / 2.0;
+        let offset_y = (height - self.height * scale) / 2.0;
+        self.transformed_strokes(|Point { x, y }| Point {
+            x: x * scale + offset_x,
+            y: y * scale + offset_y,
+        })
+    }
+
+    fn transformed_strokes(&self, transform: impl Fn(Point) -> Point) -> Vec<Stroke> {
+        self.strokes
+            .iter()
+            .map(|points| {
+                Stroke::with_details(
+                    points
+                        .iter()
+                        .map(|&TimedPoint { x, y, .. }| transform(Point { x, y }))
+                        .collect(),
+                    points.iter().map(TimedPoint::details).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Parses a recording from JSON.
+    ///
+    /// Fails if the data is not a valid recording or uses a newer version of the format.
+    pub fn from_json(data: &[u8]) -> serde_json::Result<Self> {
+        let recording: Self = serde_json::from_slice(data)?;
+        if recording.version > VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported recording version {}",
+                recording.version
+            )));
+        }
+        Ok(recording)
+    }
+
+    /// Serializes the recording to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self)
+            .expect("A recording only contains plain numbers, so serializing cannot fail")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn details(time: u64) -> PointDetails {
+        PointDetails {
+            time,
+            ..Default::default()
+        }
+    }
+
+    fn strokes() -> Vec<Stroke> {
+        vec![
+            Stroke::with_details(
+                vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }],
+                vec![
+                    details(0),
+                    PointDetails {
+                        time: 16,
+                        pressure: Some(0.5),
+                        tilt: Some((0.25, -0.5)),
+                    },
+                ],
+            ),
+            Stroke::with_details(vec![Point { x: 5.0, y: 6.0 }], vec![details(250)]),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        let recording = Recording::new(700.0, 400.0, &strokes());
+        assert_eq!(
+            recording.strokes,
+            vec![
+                vec![
+                    TimedPoint::new(Point { x: 1.0, y: 2.0 }, details(0)),
+                    TimedPoint {
+                        x: 3.0,
+                        y: 4.0,
+                        time: 16,
+                        pressure: Some(0.5),
+                        tilt: Some((0.25, -0.5)),
+                    }
+                ],
+                vec![TimedPoint::new(Point { x: 5.0, y: 6.0 }, details(250))]
+            ]
+        );
+        assert_eq!(recording.strokes(), strokes());
+    }
+
+    #[test]
+    fn test_new_without_details() {
+        let mut strokes = strokes();
+        strokes.push(Stroke::new(vec![Point { x: 7.0, y: 8.0 }]));
+        let recording = Recording::new(700.0, 400.0, &strokes);
+        // missing details use the previous time
+        assert_eq!(
+            recording.strokes[2],
+            vec![TimedPoint::new(Point { x: 7.0, y: 8.0 }, details(250))]
+        );
+    }
+
+    #[test]
+    fn test_strokes_on_canvas() {
+        let recording = Recording::new(700.0, 400.0, &strokes());
+        assert_eq!(recording.strokes_on_canvas(700.0, 400.0), strokes());
+
+        // half the height limits the scale, the drawing is centered horizontally
+        let scaled = recording.strokes_on_canvas(700.0, 200.0);
+        let points: Vec<_> = scaled.iter().flat_map(|s| s.points().copied()).collect();
+        assert_eq!(
+            points,
+            vec![
+                Point { x: 175.5, y: 1.0 },
+                Point { x: 176.5, y: 2.0 },
+                Point { x: 177.5, y: 3.0 }
+            ]
+        );
+        assert!(scaled
+            .iter()
+            .zip(strokes())
+            .all(|(scaled, stroke)| scaled.details().eq(stroke.details())));
+
+        assert_eq!(recording.strokes_on_canvas(0.0, 0.0), strokes());
+        let empty = Recording::new(0.0, 0.0, &strokes());
+        assert_eq!(empty.strokes_on_canvas(700.0, 400.0), strokes());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let recording = Recording::new(700.0, 400.0, &strokes());
+        assert_eq!(
+            Recording::from_json(recording.to_json().as_bytes()).unwrap(),
+            recording
+        );
+    }
+
+    #[test]
+    fn test_json_without_stylus_data() {
+        let json = br#"{"version":1,"width":700.0,"height":400.0,"strokes":[[{"x":1.0,"y":2.0,"time":0}]]}"#;
+        let recording = Recording::from_json(json).unwrap();
+        assert_eq!(
+            recording.strokes,
+            vec![vec![TimedPoint::new(Point { x: 1.0, y: 2.0 }, details(0))]]
+        );
+        assert!(!recording.to_json().contains("pressure"));
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut recording = Recording::new(700.0, 400.0, &strokes());
+        recording.version = VERSION + 1;
+        assert!(Recording::from_json(recording.to_json().as_bytes()).is_err());
+        assert!(Recording::from_json(b"[]").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use itertools::Itertools;
+
+use super::symbol::{dedup_aliases, symbols};
+
+/// Finds all symbols whose command, one of its aliases, its description or one of its keywords
+/// matches the `query`, with the best matches first.
+///
+/// The leading backslash of the query is optional and matching ignores the case, although exact
+/// matches are ranked first, followed by prefix matches and all other matches. Symbols only
+/// matching by their description or keywords come last, which are matched both untranslated
+/// and translated by `translate`. Aliases of the same glyph are only listed once.
+pub fn search(query: &str, translate: impl Fn(&'static str) -> String) -> Vec<&'static str> {
+    let query = query.trim().trim_start_matches('\\');
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let lowercase_query = query.to_lowercase();
+
+    let mut ids = symbols()
+        .filter_map(|(id, symbol)| {
+            let rank = std::iter::once(symbol.command)
+                .chain(symbol.aliases.iter().copied())
+                .filter_map(|command| rank(query, &lowercase_query, command))
+                .min()
+                .or_else(|| {
+                    symbol
+                        .description
+                        .iter()
+                        .chain(symbol.keywords)
+                        .any(|&text| {
+                            text.to_lowercase().contains(&lowercase_query)
+                                || translate(text).to_lowercase().contains(&lowercase_query)
+                        })
+                        .then_some(DESCRIPTION_RANK)
+                })?;
+            Some((rank, symbol.command.len(), symbol.command, id))
+        })
+        .sorted()
+        .map(|(_, _, _, id)| id)
+        .collect_vec();
+    dedup_aliases(&mut ids);
+    ids
+}
+
+/// Rank of symbols, which only match by their description or keywords.
+const DESCRIPTION_RANK: u8 = 4;
+
+/// Returns how well the `command` matches the query, lower is better,
+/// or `None` if it does not match at all.
+fn rank(query: &str, lowercase_query: &str, command: &str) -> Option<u8> {
+    let command = command.trim_start_matches('\\');
+    let lowercase_command = command.to_lowercase();
+    if command == query {
+        Some(0)
+    } else if lowercase_command == lowercase_query {
+        Some(1)
+    } else if lowercase_command.starts_with(lowercase_query) {
+        Some(2)
+    } else if lowercase_command.contains(lowercase_query) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::Symbol;
+
+    fn commands(query: &str) -> Vec<&'static str> {
+        search(query, str::to_owned)
+            .into_iter()
+            .map(|id| Symbol::from_id(id).unwrap().command)
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_query() {
+        assert!(search("", str::to_owned).is_empty());
+        assert!(search(" \\ ", str::to_owned).is_empty());
+    }
+
+    #[test]
+    fn test_exact_match_first() {
+        assert_eq!(commands("\\alpha")[0], "\\alpha");
+        assert_eq!(commands("alpha")[0], "\\alpha");
+        assert_eq!(commands("Leftarrow")[0], "\\Leftarrow");
+        assert_eq!(commands("leftarrow")[0], "\\leftarrow");
+    }
+
+    #[test]
+    fn test_prefix_before_substring() {
+        // symbols matching by their command come before those matching by their description
+        let matches_command = |id: &&str| {
+            let symbol = Symbol::from_id(id).unwrap();
+            std::iter::once(symbol.command)
+                .chain(symbol.aliases.iter().copied())
+                .any(|command| command.to_lowercase().contains("arrow"))
+        };
+        let ids = search("arrow", str::to_owned);
+        let first_description = ids.iter().position(|id| !matches_command(id)).unwrap();
+        assert!(first_description > 0);
+        assert!(!ids[first_description..].iter().any(matches_command));
+
+        let is_prefix = |command: &&str| command.to_lowercase().starts_with("\\sub");
+        let commands = self::commands("sub");
+        let first_substring = commands.iter().position(|c| !is_prefix(c)).unwrap();
+        assert!(first_substring > 0);
+        assert!(!commands[first_substring..].iter().any(is_prefix));
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(commands("\\le")[0], "\\leq");
+        assert_eq!(commands("to")[0], "\\rightarrow");
+    }
+
+    #[test]
+    fn test_dedup_aliases() {
+        let commands = commands("dagger");
+        assert!(commands.contains(&"\\dagger"));
+        assert!(!commands.contains(&"\\dag"));
+
+        // the same command from different packages is not an alias
+        let commands = self::commands("iint");
+        assert_eq!(commands.iter().filter(|&&c| c == "\\iint").count(), 2);
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(
+            commands("rightwards double arrow"),
+            commands("Rightwards Double Arrow")
+        );
+        assert!(commands("rightwards double arrow").contains(&"\\Rightarrow"));
+        assert!(commands("implies").contains(&"\\Longrightarrow"));
+        // commands are ranked before descriptions
+        assert_eq!(commands("exists")[0], "\\exists");
+    }
+
+    #[test]
+    fn test_translated_description() {
+        let translate = |text: &str| text.replace("there exists", "es gibt");
+        let ids = search("es gibt", translate);
+        assert_eq!(Symbol::from_id(ids[0]).unwrap().command, "\\exists");
+    }
+}
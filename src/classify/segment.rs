@@ -0,0 +1,124 @@
+use itertools::Itertools;
+
+use super::{Stroke, Symbol};
+
+/// Maximal horizontal gap between two strokes of the same symbol,
+/// relative to the height of the whole drawing.
+const MAX_GAP: f64 = 0.1;
+
+/// Splits the strokes into groups of spatially separated symbols, ordered from left to right.
+///
+/// Strokes belong to the same symbol if they overlap horizontally, allowing for a small gap.
+/// This keeps symbols consisting of vertically stacked strokes, such as `=` or `\leq`,
+/// together when writing a formula on a single line.
+/// Within a group, the strokes keep the order they were drawn in.
+pub fn segment(strokes: Vec<Stroke>) -> Vec<Vec<Stroke>> {
+    let mut strokes = strokes
+        .into_iter()
+        .filter(|stroke| !stroke.is_empty())
+        .enumerate()
+        .map(|(index, stroke)| (index, stroke.bounding_box(), stroke))
+        .collect_vec();
+    if strokes.is_empty() {
+        return Vec::new();
+    }
+
+    let top = strokes
+        .iter()
+        .map(|(_, bb, _)| bb.lower_left.y)
+        .fold(f64::INFINITY, f64::min);
+    let bottom = strokes
+        .iter()
+        .map(|(_, bb, _)| bb.upper_right.y)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let gap = MAX_GAP * (bottom - top);
+
+    strokes.sort_by(|(_, a, _), (_, b, _)| a.lower_left.x.total_cmp(&b.lower_left.x));
+
+    // right edge and strokes of each segment
+    let mut segments: Vec<(f64, Vec<(usize, Stroke)>)> = Vec::new();
+    for (index, bb, stroke) in strokes {
+        match segments.last_mut() {
+            Some((right, segment)) if bb.lower_left.x <= *right + gap => {
+                *right = right.max(bb.upper_right.x);
+                segment.push((index, stroke));
+            }
+            _ => segments.push((bb.upper_right.x, vec![(index, stroke)])),
+        }
+    }
+
+    segments
+        .into_iter()
+        .map(|(_, segment)| {
+            segment
+                .into_iter()
+                .sorted_by_key(|(index, _)| *index)
+                .map(|(_, stroke)| stroke)
+                .collect()
+        })
+        .collect()
+}
+
+/// Joins the commands of the symbols with the given ids into a single string,
+/// e.g. `\alpha \leq \beta`.
+pub fn transcribe(ids: &[&str]) -> String {
+    ids.iter()
+        .filter_map(|id| Symbol::from_id(id))
+        .map(|symbol| symbol.command)
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::Point;
+
+    fn line(x0: f64, y0: f64, x1: f64, y1: f64) -> Stroke {
+        Stroke::new(vec![Point { x: x0, y: y0 }, Point { x: x1, y: y1 }])
+    }
+
+    #[test]
+    fn test_segment_empty() {
+        assert!(segment(Vec::new()).is_empty());
+        assert!(segment(vec![Stroke::default()]).is_empty());
+    }
+
+    #[test]
+    fn test_segment_single_symbol() {
+        // `=` consists of two stacked strokes
+        let strokes = vec![line(0.0, 40.0, 50.0, 40.0), line(0.0, 60.0, 50.0, 60.0)];
+        assert_eq!(segment(strokes.clone()), vec![strokes]);
+    }
+
+    #[test]
+    fn test_segment_multiple_symbols() {
+        let a = line(0.0, 0.0, 40.0, 100.0);
+        let b = line(100.0, 40.0, 150.0, 40.0);
+        let c = line(100.0, 60.0, 150.0, 60.0);
+        let d = line(200.0, 0.0, 240.0, 100.0);
+
+        // drawn out of order
+        let strokes = vec![d.clone(), b.clone(), a.clone(), c.clone()];
+        assert_eq!(segment(strokes), vec![vec![a], vec![b, c], vec![d]]);
+    }
+
+    #[test]
+    fn test_segment_small_gap() {
+        // strokes with a gap smaller than 10% of the height belong together
+        let a = line(0.0, 0.0, 40.0, 100.0);
+        let b = line(45.0, 0.0, 80.0, 100.0);
+        assert_eq!(segment(vec![a.clone(), b.clone()]), vec![vec![a, b]]);
+    }
+
+    #[test]
+    fn test_transcribe() {
+        assert_eq!(
+            transcribe(&[
+                "latex2e-textasciicircum",
+                "invalid",
+                "latex2e-textasciicircum"
+            ]),
+            "\\textasciicircum \\textasciicircum"
+        );
+    }
+}
@@ -1,19 +1,47 @@
-use super::{point::Point, rect::Rect};
+use super::{
+    point::{Point, PointDetails},
+    rect::Rect,
+};
 use itertools::Itertools;
-use serde::Serialize;
-use std::collections::VecDeque;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // Original code from:
 // https://github.com/FineFindus/detexify-rust/blob/311002feb0519f483ef1f9cc8206648286128ff5/src/stroke.rs
 
 /// A list of connectect [`Point`]s.
-#[derive(Clone, Debug, Default, PartialEq, Serialize)]
-pub struct Stroke(Vec<Point>);
+///
+/// The stroke can optionally store [`PointDetails`] for each point. They are either
+/// empty or contain exactly one entry per point, and are dropped once the points
+/// are resampled during preprocessing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stroke(Vec<Point>, Vec<PointDetails>);
+
+impl Serialize for Stroke {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // only the points are part of the uploaded data
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Stroke {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Stroke::new)
+    }
+}
 
 impl Stroke {
     /// Create a new [`Stoke`] with the given points.
     pub const fn new(points: Vec<Point>) -> Self {
-        Self(points)
+        Self(points, Vec::new())
+    }
+
+    /// Create a new [`Stroke`] with the given points and their details.
+    ///
+    /// # Panics
+    /// Panics if there is not exactly one entry of `details` per point.
+    pub fn with_details(points: Vec<Point>, details: Vec<PointDetails>) -> Self {
+        assert_eq!(points.len(), details.len());
+        Self(points, details)
     }
 
     /// Returns true if the stroke does not contain any [`Point`]s.
@@ -26,14 +54,38 @@ impl Stroke {
         self.0.iter()
     }
 
+    /// Returns an iterator that allows modifying each point of the stroke.
+    pub(super) fn points_mut(&mut self) -> impl Iterator<Item = &mut Point> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over the details of each point,
+    /// or an empty iterator if the stroke does not have any.
+    pub fn details(&self) -> impl Iterator<Item = &PointDetails> {
+        self.1.iter()
+    }
+
     /// Clears the stroke, removing all points.
     pub fn clear(&mut self) {
         self.0.clear();
+        self.1.clear();
     }
 
     /// Appends a new [`Point`] to the end of the stroke.
     pub fn add_point(&mut self, point: Point) {
-        self.0.push(point)
+        self.0.push(point);
+        if !self.1.is_empty() {
+            self.1.push(PointDetails::default());
+        }
+    }
+
+    /// Appends a new [`Point`] with the given details to the end of the stroke.
+    ///
+    /// Previous points without details use the default details.
+    pub fn add_point_with_details(&mut self, point: Point, details: PointDetails) {
+        self.1.resize(self.0.len(), PointDetails::default());
+        self.0.push(point);
+        self.1.push(details);
     }
 
     /// Computes the total length of the strokes.
@@ -137,6 +189,7 @@ impl Stroke {
     /// Removes duplicate (points that are nearly identical) [`Point`]s.
     pub(super) fn dedup(&mut self) {
         self.0.dedup_by(|&mut p, &mut q| p.approx_eq(q));
+        self.1.clear();
     }
 
     /// Smooths the stroke.
@@ -162,57 +215,83 @@ impl Stroke {
         self.0 = smoothed;
     }
 
-    /// Redistribute the points in the stroke to have equal distance to each other.
+    /// Resamples the stroke to `n` points, which are evenly spaced along the stroke.
+    ///
+    /// The start and end point stay the same. A stroke without any length
+    /// results in `n` copies of its point.
+    ///
+    /// # Panics
+    /// Panics if `n` is less than 2.
     pub(super) fn redistribute(&mut self, n: usize) {
-        // degenerate cases
-        if self.0.len() < 2 {
+        assert!(n >= 2);
+        let (Some(&first), Some(&last)) = (self.0.first(), self.0.last()) else {
             return;
-        }
-
-        assert!(n > 2);
-
-        let dist = self.length() / (n as f64 - 1.0);
-        assert!(dist > 0.0);
-
-        let mut left = dist;
-
-        let mut distributed = Vec::with_capacity(self.0.len());
-        let mut work_list: VecDeque<Point> = self.0.iter().cloned().collect();
-
-        distributed.push(self.0[0]);
+        };
 
-        let mut i = 100;
+        let length = self.length();
+        self.1.clear();
+        if length == 0.0 {
+            self.0 = vec![first; n];
+            return;
+        }
 
-        while work_list.len() >= 2 {
-            i -= 1;
-            if i == 0 {
-                break;
+        let step = length / (n - 1) as f64;
+        let mut distributed = Vec::with_capacity(n);
+        distributed.push(first);
+
+        let mut segments = self.0.windows(2).map(|segment| (segment[0], segment[1]));
+        let mut current = segments.next();
+        // length of the stroke before the current segment
+        let mut travelled = 0.0;
+        for i in 1..n - 1 {
+            let target = step * i as f64;
+            // advance to the segment containing the target
+            while let Some((p, q)) = current {
+                let d = p.euclidean_distance(&q);
+                if travelled + d >= target {
+                    break;
+                }
+                travelled += d;
+                current = segments.next();
             }
 
-            let p = work_list.pop_front().unwrap();
-            let q = work_list[0];
-
-            let dir = q - p;
-            let d = dir.norm();
-
-            if d < left {
-                left -= d;
-            } else {
-                let ins = p + (dir * (left / d));
-                left = dist;
-                work_list.push_front(ins);
-                distributed.push(ins);
-            }
+            let point = match current {
+                Some((p, q)) => {
+                    let d = p.euclidean_distance(&q);
+                    if d == 0.0 {
+                        p
+                    } else {
+                        p + (q - p) * ((target - travelled) / d)
+                    }
+                }
+                // only reachable due to rounding errors at the very end
+                None => last,
+            };
+            distributed.push(point);
         }
 
-        distributed.extend(work_list);
-
+        distributed.push(last);
         self.0 = distributed;
     }
 
     /// Filter the points to only contain dominant points,
-    /// based on the given alpha angle threshold.
+    /// i.e. the start and end point and all points at which the stroke turns
+    /// by at least the given alpha angle (in radians).
     pub(super) fn dominant(&mut self, alpha: f64) {
+        self.filter_turning_points(|p, q, r| p.angle(q, r) >= alpha);
+    }
+
+    /// Filter the points based on [`Point::legacy_angle`], which keeps
+    /// nearly all points.
+    ///
+    /// Only kept for preprocessing input for models trained with it.
+    pub(super) fn legacy_dominant(&mut self, alpha: f64) {
+        self.filter_turning_points(|p, q, r| p.legacy_angle(q, r) <= alpha);
+    }
+
+    /// Keeps the start and end point, and all points `q` for which the predicate
+    /// with the previous point `p` and the next point `r` returns true.
+    fn filter_turning_points<F: Fn(Point, Point, Point) -> bool>(&mut self, predicate: F) {
         if self.0.len() < 3 {
             return;
         }
@@ -222,23 +301,50 @@ impl Stroke {
         new_stroke.extend(
             self.points()
                 .tuple_windows()
-                //TODO: use >= or <=?
-                .filter(|(&p, &q, &r)| p.angle(q, r) <= alpha)
+                .filter(|(&p, &q, &r)| predicate(p, q, r))
                 .map(|(&_p, &q, &_r)| q),
         );
         new_stroke.push(*self.0.last().unwrap());
 
         self.0 = new_stroke;
+        self.1.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::classify::point::{ONE_POINT, ZERO_POINT};
+    use crate::classify::{
+        augment::SeededRng,
+        point::{ONE_POINT, ZERO_POINT},
+    };
 
     use super::*;
 
     const HALF_POINT: Point = Point { x: 0.5, y: 0.5 };
+    const EPSILON: f64 = 1e-9;
+
+    /// Returns a random stroke with `len` points within a 100×100 square.
+    fn random_stroke(rng: &mut SeededRng, len: usize) -> Stroke {
+        Stroke::new(
+            (0..len)
+                .map(|_| Point {
+                    x: rng.next_f64() * 100.0,
+                    y: rng.next_f64() * 100.0,
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the distance between `p` and the line segment from `a` to `b`.
+    fn segment_distance(p: Point, a: Point, b: Point) -> f64 {
+        let ab = b - a;
+        let len = ab.dot(&ab);
+        if len == 0.0 {
+            return p.euclidean_distance(&a);
+        }
+        let t = ((p - a).dot(&ab) / len).clamp(0.0, 1.0);
+        p.euclidean_distance(&(a + ab * t))
+    }
 
     #[test]
     fn test_bounding_box() {
@@ -305,4 +411,253 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn test_dominant_straight_line() {
+        let mut s = Stroke::new(
+            (0..10)
+                .map(|i| Point {
+                    x: i as f64,
+                    y: 0.0,
+                })
+                .collect(),
+        );
+        s.dominant(0.1);
+        assert_eq!(s, Stroke::new(vec![ZERO_POINT, Point { x: 9.0, y: 0.0 }]));
+    }
+
+    #[test]
+    fn test_dominant_keeps_corners() {
+        let corner = Point { x: 2.0, y: 0.0 };
+        let mut s = Stroke::new(vec![
+            ZERO_POINT,
+            Point { x: 1.0, y: 0.0 },
+            corner,
+            Point { x: 2.0, y: 1.0 },
+            Point { x: 2.0, y: 2.0 },
+        ]);
+        s.dominant(0.1);
+        assert_eq!(
+            s,
+            Stroke::new(vec![ZERO_POINT, corner, Point { x: 2.0, y: 2.0 }])
+        );
+    }
+
+    #[test]
+    fn test_dominant_properties() {
+        let mut rng = SeededRng::new(1);
+        let alpha = std::f64::consts::PI / 12.0;
+        for len in (0..200).map(|i| i % 20) {
+            let original = random_stroke(&mut rng, len);
+            let mut s = original.clone();
+            s.dominant(alpha);
+
+            assert!(s.0.len() <= original.0.len());
+            assert_eq!(s.0.first(), original.0.first());
+            assert_eq!(s.0.last(), original.0.last());
+            if len < 3 {
+                assert_eq!(s, original);
+                continue;
+            }
+
+            // the result is a subsequence of the original, only containing turning points
+            let mut positions = Vec::new();
+            let mut original_points = original.0.iter().enumerate();
+            for point in &s.0 {
+                let (i, _) = original_points.find(|(_, p)| *p == point).unwrap();
+                positions.push(i);
+            }
+            for &i in &positions[1..positions.len() - 1] {
+                let (p, q, r) = (original.0[i - 1], original.0[i], original.0[i + 1]);
+                assert!(p.angle(q, r) >= alpha);
+            }
+        }
+    }
+
+    #[test]
+    fn test_redistribute_properties() {
+        let mut rng = SeededRng::new(2);
+        for len in (0..200).map(|i| 2 + i % 7) {
+            let original = random_stroke(&mut rng, len);
+            let mut s = original.clone();
+            s.redistribute(10);
+
+            assert_eq!(s.0.len(), 10);
+            assert_eq!(s.0.first(), original.0.first());
+            assert_eq!(s.0.last(), original.0.last());
+
+            // all points lie on the original stroke
+            for &p in &s.0 {
+                let distance = original
+                    .points()
+                    .tuple_windows()
+                    .map(|(&a, &b)| segment_distance(p, a, b))
+                    .fold(f64::INFINITY, f64::min);
+                assert!(distance < EPSILON);
+            }
+
+            // points are at most the target distance apart
+            let dist = original.length() / 9.0;
+            for (p, q) in s.points().tuple_windows() {
+                assert!(p.euclidean_distance(q) <= dist + EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_redistribute_long_stroke() {
+        // far more points than the previous iteration limit
+        let mut s = Stroke::new(
+            (0..=1000)
+                .map(|i| Point {
+                    x: i as f64,
+                    y: 0.0,
+                })
+                .collect(),
+        );
+        s.redistribute(11);
+        assert_eq!(s.0.len(), 11);
+        for (i, p) in s.points().enumerate() {
+            assert!(p.approx_eq(Point {
+                x: i as f64 * 100.0,
+                y: 0.0
+            }));
+        }
+    }
+
+    #[test]
+    fn test_redistribute_uneven_points() {
+        let mut s = Stroke::new(vec![
+            ZERO_POINT,
+            Point { x: 0.5, y: 0.0 },
+            Point { x: 0.6, y: 0.0 },
+            Point { x: 4.0, y: 0.0 },
+        ]);
+        s.redistribute(5);
+        let expected = (0..5).map(|i| Point {
+            x: i as f64,
+            y: 0.0,
+        });
+        for (p, q) in s.points().zip(expected) {
+            assert!(p.approx_eq(q));
+        }
+    }
+
+    #[test]
+    fn test_redistribute_self_intersecting() {
+        // a closed square, ending at the start
+        let mut s = Stroke::new(vec![
+            ZERO_POINT,
+            Point { x: 1.0, y: 0.0 },
+            ONE_POINT,
+            Point { x: 0.0, y: 1.0 },
+            ZERO_POINT,
+        ]);
+        s.redistribute(9);
+        assert_eq!(s.0.len(), 9);
+        for (p, q) in s.points().tuple_windows() {
+            assert!((p.euclidean_distance(q) - 0.5).abs() < EPSILON);
+        }
+        assert_eq!(s.0[0], ZERO_POINT);
+        assert!(s.0[4].approx_eq(ONE_POINT));
+        assert_eq!(s.0[8], ZERO_POINT);
+    }
+
+    #[test]
+    fn test_redistribute_zero_length() {
+        let mut s = Stroke::new(vec![HALF_POINT; 3]);
+        s.redistribute(10);
+        assert_eq!(s, Stroke::new(vec![HALF_POINT; 10]));
+
+        let mut s = Stroke::new(vec![HALF_POINT]);
+        s.redistribute(10);
+        assert_eq!(s, Stroke::new(vec![HALF_POINT; 10]));
+
+        let mut s = Stroke::default();
+        s.redistribute(10);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_smooth_properties() {
+        let mut rng = SeededRng::new(3);
+        for len in (0..200).map(|i| i % 20) {
+            let original = random_stroke(&mut rng, len);
+            let mut s = original.clone();
+            s.smooth();
+
+            assert_eq!(s.0.len(), original.0.len());
+            assert_eq!(s.0.first(), original.0.first());
+            assert_eq!(s.0.last(), original.0.last());
+            if len == 0 {
+                continue;
+            }
+
+            // averaging never moves points outside of the bounding box
+            let bb = original.bounding_box();
+            for p in s.points() {
+                assert!(p.x >= bb.lower_left.x - EPSILON && p.x <= bb.upper_right.x + EPSILON);
+                assert!(p.y >= bb.lower_left.y - EPSILON && p.y <= bb.upper_right.y + EPSILON);
+            }
+        }
+
+        // evenly spaced points on a line are not changed
+        let line = Stroke::new(
+            (0..10)
+                .map(|i| Point {
+                    x: i as f64,
+                    y: 0.0,
+                })
+                .collect(),
+        );
+        let mut s = line.clone();
+        s.smooth();
+        for (p, q) in s.points().zip(line.points()) {
+            assert!(p.approx_eq(*q));
+        }
+    }
+
+    #[test]
+    fn test_add_point_with_details() {
+        let details = PointDetails {
+            time: 16,
+            pressure: Some(0.5),
+            tilt: None,
+        };
+        let mut s = Stroke::default();
+        s.add_point(ZERO_POINT);
+        assert_eq!(s.details().count(), 0);
+
+        // earlier points are filled with default details
+        s.add_point_with_details(ONE_POINT, details);
+        s.add_point(HALF_POINT);
+        assert_eq!(
+            s.details().copied().collect::<Vec<_>>(),
+            vec![PointDetails::default(), details, PointDetails::default()]
+        );
+
+        // resampling drops the details
+        s.redistribute(5);
+        assert_eq!(s.details().count(), 0);
+    }
+
+    #[test]
+    fn test_serialize_only_points() {
+        let s = Stroke::with_details(
+            vec![ONE_POINT],
+            vec![PointDetails {
+                time: 3,
+                pressure: Some(0.2),
+                tilt: Some((0.1, -0.1)),
+            }],
+        );
+        assert_eq!(
+            serde_json::to_string(&s).unwrap(),
+            serde_json::to_string(&Stroke::new(vec![ONE_POINT])).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&s).unwrap(),
+            "[{\"x\":1.0,\"y\":1.0}]"
+        );
+    }
 }
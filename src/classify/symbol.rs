@@ -1,6 +1,10 @@
-use base64::Engine;
+use itertools::Itertools;
+
+use super::classifier::LABELS;
+use super::{custom, id};
 
 include!(concat!(env!("OUT_DIR"), "/symbol_table.rs"));
+include!(concat!(env!("OUT_DIR"), "/unicode_table.rs"));
 
 /// Amount of available symbols
 pub static SYMBOL_COUNT: usize = SYMBOL_TABLE.len();
@@ -15,44 +19,235 @@ pub struct Symbol {
     pub command: &'static str,
     /// Package which the symbol belongs to.
     pub package: &'static str,
-    /// Font encoding used for the symbol.
-    font_encoding: &'static str,
+    /// Font encoding the symbol is available in, e.g. `T1`. Most symbols use the default
+    /// `OT1` encoding.
+    pub font_encoding: &'static str,
     /// Whether the symbol is available in text mode.
     pub text_mode: bool,
     /// Whether the symbol is available in math mode.
     pub math_mode: bool,
+    /// Other commands producing the same glyph, which are not part of the symbol table,
+    /// e.g. `\le` for `\leq`.
+    pub aliases: &'static [&'static str],
+    /// Preferred command for the glyph, which is the same as `command` unless the symbol
+    /// is an alias of another symbol in the table, e.g. `\dagger` for `\dag`.
+    pub canonical: &'static str,
+    /// Human-readable name of the symbol, e.g. "rightwards double arrow".
+    ///
+    /// It is not translated, use `gettext` before showing it to the user.
+    pub description: Option<&'static str>,
+    /// Additional words the symbol can be found by, e.g. "implies", not translated.
+    pub keywords: &'static [&'static str],
+    /// Table of `symbols.yaml` the symbol is listed in, e.g. "Binary Operators".
+    pub table: &'static str,
 }
 
 impl Symbol {
-    /// Returns the symbol that the `id` specifies.
+    /// Create a new custom symbol, see [`custom::CustomSymbol`].
+    pub(super) fn new_custom(
+        command: &'static str,
+        package: &'static str,
+        text_mode: bool,
+        math_mode: bool,
+    ) -> Self {
+        Self {
+            command,
+            package,
+            font_encoding: custom::FONT_ENCODING,
+            text_mode,
+            math_mode,
+            aliases: &[],
+            canonical: command,
+            description: None,
+            keywords: &[],
+            table: custom::TABLE,
+        }
+    }
+
+    /// Returns the symbol that the `id` specifies, including custom symbols.
+    ///
+    /// Legacy ids, see [`Symbol::legacy_id`], are accepted as well.
     pub fn from_id(id: &str) -> Option<Self> {
-        SYMBOL_TABLE.get(id).cloned()
+        SYMBOL_TABLE
+            .get(id)
+            .or_else(|| custom::get(id))
+            .or_else(|| SYMBOL_TABLE.get(LEGACY_IDS.get(id)?))
+            .cloned()
     }
 
-    /// Returns the `id` of the symbol.
+    /// Returns the `id` of the symbol, e.g. `amssymb-mathbb-R`.
     pub fn id(&self) -> &'static str {
-        let id = format!(
-            "{}-{}-{}",
-            self.package,
-            self.font_encoding,
-            self.command.replace('\\', "_")
-        );
-        let key = base64::prelude::BASE64_STANDARD.encode(id);
+        let key = id::id(self.package, self.font_encoding, self.command);
+        if self.is_custom() {
+            return custom::intern(&key);
+        }
         // SAFETY: safe to unwrap, since key must be valid, as it is only possible to get a Symbol
         // from the symbol table
         SYMBOL_TABLE.get_key(&key).unwrap()
     }
+
+    /// Returns the id the symbol had before ids became human-readable,
+    /// which the training data of the model is labelled with.
+    pub fn legacy_id(&self) -> String {
+        id::legacy_id(self.package, self.font_encoding, self.command)
+    }
+
+    /// Returns the name of the icon showing the symbol.
+    ///
+    /// Custom symbols do not have their own icon, so they all use the same one.
+    pub fn icon_name(&self) -> String {
+        if self.is_custom() {
+            return custom::ICON_NAME.to_owned();
+        }
+        format!("{}-symbolic", self.id())
+    }
+
+    /// Returns all other commands which produce the same glyph, starting with
+    /// the canonical command.
+    pub fn alternatives(&self) -> Vec<&'static str> {
+        let table_aliases = SYMBOL_TABLE
+            .values()
+            .filter(|symbol| symbol.canonical == self.canonical)
+            .map(|symbol| symbol.command);
+        std::iter::once(self.canonical)
+            .chain(self.aliases.iter().copied())
+            .chain(table_aliases)
+            .filter(|&command| command != self.command)
+            .unique()
+            .collect()
+    }
+
+    /// Returns the modes in which the symbol is available.
+    pub fn mode(&self) -> &'static str {
+        match (self.math_mode, self.text_mode) {
+            (true, true) => "mathmode & textmode",
+            (false, true) => "textmode",
+            (true, false) => "mathmode",
+            (false, false) => {
+                // a symbol has to be either math mode or textmode
+                unreachable!("Symbol {} is neither math nor textmode", self.id())
+            }
+        }
+    }
+
+    /// Returns the lines needed in the preamble to use the symbol, i.e. selecting its font
+    /// encoding and loading its package, or `None` if it is available in every document.
+    pub fn preamble(&self) -> Option<String> {
+        let mut lines = Vec::new();
+        // custom symbols do not have a real font encoding
+        if self.font_encoding != "OT1" && !self.is_custom() {
+            lines.push(format!("\\usepackage[{}]{{fontenc}}", self.font_encoding));
+        }
+        if self.package != "latex2e" {
+            lines.push(format!("\\usepackage{{{}}}", self.package));
+        }
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Returns the Unicode character of the symbol, if it has one.
+    pub fn unicode(&self) -> Option<char> {
+        UNICODE_TABLE.get(self.command).copied()
+    }
+
+    /// Returns the other symbols from the same package, ordered by their command.
+    pub fn related(&self) -> Vec<&'static Symbol> {
+        let mut related: Vec<_> = SYMBOL_TABLE
+            .values()
+            .filter(|symbol| symbol.package == self.package && *symbol != self)
+            .collect();
+        related.sort_by_key(|symbol| symbol.command);
+        related
+    }
+
+    /// Returns whether the symbol can be recognized by the classifier, i.e. it is one of the
+    /// labels of the model or a custom symbol, which is recognized by its examples.
+    ///
+    /// All other symbols can only be found by searching or browsing.
+    pub fn is_recognizable(&self) -> bool {
+        self.is_custom() || LABELS.contains(&self.id())
+    }
+
+    /// Returns whether the symbols are different commands for the same glyph, i.e. they are
+    /// linked by a `canonical` declaration in `symbols.yaml`, like `\dag` and `\dagger`.
+    ///
+    /// Commands with the same name from different packages, like `\iint` from `amsmath`
+    /// and `esint`, are not aliases, as they can look different and need different preambles.
+    pub fn is_alias_of(&self, other: &Symbol) -> bool {
+        self.command != other.command && self.canonical == other.canonical
+    }
+
+    /// Returns whether the symbol has been defined by the user, see [`custom::CustomSymbol`].
+    pub fn is_custom(&self) -> bool {
+        self.font_encoding == custom::FONT_ENCODING
+    }
+}
+
+/// Returns an iterator over the ids and symbols of the whole symbol table,
+/// followed by the custom symbols.
+pub fn symbols() -> impl Iterator<Item = (&'static str, &'static Symbol)> {
+    SYMBOL_TABLE
+        .entries()
+        .map(|(id, symbol)| (*id, symbol))
+        .chain(custom::symbols())
+}
+
+/// Returns all symbols grouped by the table of `symbols.yaml` they are listed in,
+/// in the order of the tables. The symbols are ordered by their command.
+pub fn symbols_by_table() -> Vec<(&'static str, Vec<&'static Symbol>)> {
+    let mut groups = SYMBOL_TABLE
+        .values()
+        .into_group_map_by(|symbol| symbol.table);
+    TABLES
+        .iter()
+        .filter_map(|table| {
+            let mut symbols = groups.remove(table)?;
+            symbols.sort_by_key(|symbol| symbol.command);
+            Some((*table, symbols))
+        })
+        .collect()
+}
+
+/// Returns all symbols grouped by their package, ordered by the name of the package.
+/// The symbols are ordered by their command.
+pub fn symbols_by_package() -> Vec<(&'static str, Vec<&'static Symbol>)> {
+    SYMBOL_TABLE
+        .values()
+        .into_group_map_by(|symbol| symbol.package)
+        .into_iter()
+        .sorted_by_key(|(package, _)| *package)
+        .map(|(package, mut symbols)| {
+            symbols.sort_by_key(|symbol| symbol.command);
+            (package, symbols)
+        })
+        .collect()
+}
+
+/// Removes all symbols which are aliases of an earlier symbol, see [`Symbol::is_alias_of`],
+/// keeping the order of the remaining ones.
+pub fn dedup_aliases(ids: &mut Vec<&'static str>) {
+    let mut seen: Vec<&Symbol> = Vec::new();
+    ids.retain(|id| {
+        let Some(symbol) = SYMBOL_TABLE.get(id) else {
+            return true;
+        };
+        let new = !seen.iter().any(|other| symbol.is_alias_of(other));
+        seen.push(symbol);
+        new
+    });
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::Symbol;
-    use crate::classify::symbol::SYMBOL_TABLE;
+    use itertools::Itertools;
+
+    use super::{dedup_aliases, symbols_by_package, symbols_by_table, Symbol, LABELS};
+    use crate::classify::confusion::CONFUSION_GROUPS;
+    use crate::classify::symbol::{SYMBOL_TABLE, TABLES};
 
     #[test]
     fn test_from_id() {
-        let symbol = Symbol::from_id("bGF0ZXgyZS1PVDEtX3RleHRhc2NpaWNpcmN1bQ==");
+        let symbol = Symbol::from_id("latex2e-textasciicircum");
 
         assert_eq!(
             symbol,
@@ -61,11 +256,214 @@ mod tests {
                 package: "latex2e",
                 font_encoding: "OT1",
                 text_mode: true,
-                math_mode: false
+                math_mode: false,
+                aliases: &[],
+                canonical: "\\textasciicircum",
+                description: None,
+                keywords: &[],
+                table: "Predefined LaTeX 2ε Text-mode Commands",
             })
         );
     }
 
+    #[test]
+    fn test_legacy_id() {
+        let symbol = Symbol::from_id("bGF0ZXgyZS1PVDEtX3RleHRhc2NpaWNpcmN1bQ==").unwrap();
+        assert_eq!(symbol.id(), "latex2e-textasciicircum");
+        assert_eq!(
+            symbol.legacy_id(),
+            "bGF0ZXgyZS1PVDEtX3RleHRhc2NpaWNpcmN1bQ=="
+        );
+
+        for symbol in SYMBOL_TABLE.values() {
+            assert_eq!(&Symbol::from_id(&symbol.legacy_id()).unwrap(), symbol);
+        }
+    }
+
+    #[test]
+    fn test_details() {
+        let symbol = Symbol::from_id("latex2e-alpha").unwrap();
+        assert_eq!(symbol.command, "\\alpha");
+        assert_eq!(symbol.mode(), "mathmode");
+        assert_eq!(symbol.preamble(), None);
+        assert_eq!(symbol.unicode(), Some('α'));
+
+        let related = symbol.related();
+        assert!(!related.contains(&&symbol));
+        assert!(related.iter().all(|related| related.package == "latex2e"));
+        assert!(related.is_sorted_by_key(|related| related.command));
+    }
+
+    #[test]
+    fn test_preamble() {
+        let amssymb = SYMBOL_TABLE
+            .values()
+            .find(|symbol| symbol.package == "amssymb")
+            .unwrap();
+        assert_eq!(amssymb.preamble().as_deref(), Some("\\usepackage{amssymb}"));
+
+        let guillemot = Symbol::from_id("latex2e-t1-guillemotleft").unwrap();
+        assert_eq!(guillemot.font_encoding, "T1");
+        assert_eq!(
+            guillemot.preamble().as_deref(),
+            Some("\\usepackage[T1]{fontenc}")
+        );
+
+        let alpha = Symbol::from_id("latex2e-alpha").unwrap();
+        assert_eq!(alpha.preamble(), None);
+    }
+
+    /// Returns the first symbol with the given command.
+    fn symbol(command: &str) -> &'static Symbol {
+        SYMBOL_TABLE
+            .values()
+            .find(|symbol| symbol.command == command)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_aliases() {
+        let leq = symbol("\\leq");
+        assert_eq!(leq.aliases, &["\\le"]);
+        assert_eq!(leq.canonical, "\\leq");
+        assert_eq!(leq.alternatives(), vec!["\\le"]);
+
+        let dag = symbol("\\dag");
+        assert_eq!(dag.canonical, "\\dagger");
+        assert_eq!(dag.alternatives(), vec!["\\dagger", "\\textdagger"]);
+    }
+
+    #[test]
+    fn test_dedup_aliases() {
+        let dag = symbol("\\dag").id();
+        let dagger = symbol("\\dagger").id();
+        let leq = symbol("\\leq").id();
+        let mut ids = vec![dag, leq, dagger];
+        dedup_aliases(&mut ids);
+        assert_eq!(ids, vec![dag, leq]);
+
+        // the same command from different packages is not an alias
+        let mut iints = SYMBOL_TABLE
+            .values()
+            .filter(|symbol| symbol.command == "\\iint")
+            .map(|symbol| symbol.id())
+            .collect_vec();
+        assert!(iints.len() >= 2);
+        let len = iints.len();
+        dedup_aliases(&mut iints);
+        assert_eq!(iints.len(), len);
+    }
+
+    #[test]
+    fn test_canonical_is_in_table() {
+        for symbol in SYMBOL_TABLE.values() {
+            assert!(
+                SYMBOL_TABLE
+                    .values()
+                    .any(|other| other.command == symbol.canonical
+                        && other.canonical == other.command)
+            );
+        }
+    }
+
+    #[test]
+    fn test_descriptions() {
+        let alpha = symbol("\\alpha");
+        assert_eq!(alpha.description, Some("alpha"));
+        assert_eq!(alpha.keywords, &["greek"]);
+
+        // shared by all symbols with the same command
+        for symbol in SYMBOL_TABLE.values().filter(|s| s.command == "\\leq") {
+            assert_eq!(symbol.description, Some("less-than or equal to"));
+        }
+    }
+
+    #[test]
+    fn test_strings_are_translatable() {
+        let strings = include_str!("../../data/symbol-strings.h");
+        let missing = SYMBOL_TABLE
+            .values()
+            .flat_map(|symbol| symbol.description.iter().chain(symbol.keywords))
+            .chain(TABLES)
+            .chain(
+                CONFUSION_GROUPS
+                    .iter()
+                    .flat_map(|group| group.iter().map(|(_, difference)| difference)),
+            )
+            .map(|string| format!("N_(\"{}\");", string))
+            .filter(|line| !strings.lines().any(|l| l == line))
+            .unique()
+            .collect_vec();
+        assert!(
+            missing.is_empty(),
+            "Add these lines to data/symbol-strings.h:\n{}",
+            missing.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_labels_are_in_symbol_table() {
+        let missing = LABELS
+            .iter()
+            .filter(|label| Symbol::from_id(label).is_none())
+            .collect_vec();
+        assert!(missing.is_empty(), "Labels without symbol: {:?}", missing);
+    }
+
+    #[test]
+    fn test_unrecognizable_symbols() {
+        let unrecognizable = SYMBOL_TABLE
+            .values()
+            .filter(|symbol| !symbol.is_recognizable())
+            .map(|symbol| symbol.command)
+            .sorted()
+            .collect_vec();
+        // the model has not been trained on lowercase Fraktur letters
+        let expected = ('a'..='z')
+            .map(|letter| format!("\\mathfrak{{{}}}", letter))
+            .collect_vec();
+        assert_eq!(
+            unrecognizable, expected,
+            "Symbols that cannot be recognized have changed"
+        );
+        assert_eq!(SYMBOL_TABLE.len() - unrecognizable.len(), LABELS.len());
+    }
+
+    #[test]
+    fn test_symbols_by_table() {
+        let tables = symbols_by_table();
+        assert_eq!(tables.iter().map(|(table, _)| *table).collect_vec(), TABLES);
+        assert_eq!(
+            tables
+                .iter()
+                .map(|(_, symbols)| symbols.len())
+                .sum::<usize>(),
+            SYMBOL_TABLE.len()
+        );
+        let (_, greek) = tables
+            .iter()
+            .find(|(table, _)| *table == "Greek Letters")
+            .unwrap();
+        assert!(greek.iter().any(|symbol| symbol.command == "\\alpha"));
+        assert!(greek.is_sorted_by_key(|symbol| symbol.command));
+    }
+
+    #[test]
+    fn test_symbols_by_package() {
+        let packages = symbols_by_package();
+        assert!(packages.is_sorted_by_key(|(package, _)| *package));
+        assert_eq!(
+            packages
+                .iter()
+                .map(|(_, symbols)| symbols.len())
+                .sum::<usize>(),
+            SYMBOL_TABLE.len()
+        );
+        for (package, symbols) in packages {
+            assert!(symbols.iter().all(|symbol| symbol.package == package));
+        }
+    }
+
     #[test]
     fn test_iterate_symbols() {
         assert_eq!(SYMBOL_TABLE.len(), 1098);
@@ -0,0 +1,223 @@
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use gtk::{cairo, gdk, glib};
+
+use crate::classify::{Point, Stroke};
+use crate::widgets::{stroke_parts, StrokePart, LINE_COLOR};
+
+/// Error returned when exporting a drawing.
+#[derive(Debug)]
+pub enum ExportError {
+    /// The scale is not a positive number or too small for the canvas.
+    InvalidScale(f64),
+    /// The canvas the strokes were drawn on has no area.
+    EmptyCanvas,
+    /// Drawing the image failed.
+    Render(cairo::Error),
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::InvalidScale(scale) => write!(f, "Invalid scale: {}", scale),
+            ExportError::EmptyCanvas => write!(f, "The drawing has no size"),
+            ExportError::Render(err) => write!(f, "Failed to render drawing: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<cairo::Error> for ExportError {
+    fn from(err: cairo::Error) -> Self {
+        ExportError::Render(err)
+    }
+}
+
+/// Returns the size of the exported image in pixels, i.e. the canvas size
+/// scaled by `scale` and rounded up.
+fn image_size(width: f64, height: f64, scale: f64) -> Result<(i32, i32), ExportError> {
+    if !(width.is_finite() && height.is_finite() && width > 0.0 && height > 0.0) {
+        return Err(ExportError::EmptyCanvas);
+    }
+    if !(scale.is_finite() && scale > 0.0) {
+        return Err(ExportError::InvalidScale(scale));
+    }
+    let image_width = (width * scale).ceil();
+    let image_height = (height * scale).ceil();
+    if !(1.0..=i32::MAX as f64).contains(&image_width)
+        || !(1.0..=i32::MAX as f64).contains(&image_height)
+    {
+        return Err(ExportError::InvalidScale(scale));
+    }
+    Ok((image_width as i32, image_height as i32))
+}
+
+/// Renders the strokes to an SVG image, with one element per part of a stroke,
+/// see [`stroke_parts`].
+///
+/// `width` and `height` are the size of the canvas the strokes were drawn on,
+/// the image size is scaled by `scale`.
+pub fn svg(strokes: &[Stroke], width: f64, height: f64, scale: f64) -> Result<String, ExportError> {
+    image_size(width, height, scale)?;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.2}\" height=\"{:.2}\" viewBox=\"0 0 {:.2} {:.2}\">\n",
+        width * scale,
+        height * scale,
+        width,
+        height
+    );
+    let (r, g, b) = (
+        (LINE_COLOR.red() * 255.0).round() as u8,
+        (LINE_COLOR.green() * 255.0).round() as u8,
+        (LINE_COLOR.blue() * 255.0).round() as u8,
+    );
+    // writing to a string cannot fail, so the results are ignored
+    let _ = writeln!(
+        svg,
+        "  <g fill=\"none\" stroke=\"#{r:02x}{g:02x}{b:02x}\" stroke-linecap=\"round\" stroke-linejoin=\"round\">"
+    );
+
+    for part in strokes.iter().flat_map(stroke_parts) {
+        match part {
+            StrokePart::Dot(Point { x, y }, width) => {
+                let _ = writeln!(
+                    svg,
+                    "    <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" stroke-width=\"{}\"/>",
+                    x,
+                    y,
+                    width / 2.0,
+                    width
+                );
+            }
+            StrokePart::Line(points, width) => {
+                let path = points
+                    .iter()
+                    .enumerate()
+                    .map(|(i, Point { x, y })| {
+                        format!("{}{:.2} {:.2}", if i == 0 { "M" } else { "L" }, x, y)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let _ = writeln!(svg, "    <path d=\"{}\" stroke-width=\"{}\"/>", path, width);
+            }
+        }
+    }
+
+    svg.push_str("  </g>\n</svg>\n");
+    Ok(svg)
+}
+
+/// Renders the strokes to a PNG image with a transparent background.
+///
+/// `width` and `height` are the size of the canvas the strokes were drawn on,
+/// the image size is scaled by `scale`.
+pub fn png(
+    strokes: &[Stroke],
+    width: f64,
+    height: f64,
+    scale: f64,
+) -> Result<glib::Bytes, ExportError> {
+    let (image_width, image_height) = image_size(width, height, scale)?;
+    let mut surface =
+        cairo::ImageSurface::create(cairo::Format::ARgb32, image_width, image_height)?;
+
+    {
+        let context = cairo::Context::new(&surface)?;
+        context.scale(scale, scale);
+        context.set_source_rgb(
+            LINE_COLOR.red() as f64,
+            LINE_COLOR.green() as f64,
+            LINE_COLOR.blue() as f64,
+        );
+        context.set_line_cap(cairo::LineCap::Round);
+        context.set_line_join(cairo::LineJoin::Round);
+
+        for part in strokes.iter().flat_map(stroke_parts) {
+            context.new_path();
+            let width = match part {
+                StrokePart::Dot(Point { x, y }, width) => {
+                    context.arc(x, y, width as f64 / 2.0, 0.0, 2.0 * PI);
+                    width
+                }
+                StrokePart::Line(points, width) => {
+                    if let Some((first, rest)) = points.split_first() {
+                        context.move_to(first.x, first.y);
+                        for point in rest {
+                            context.line_to(point.x, point.y);
+                        }
+                    }
+                    width
+                }
+            };
+            context.set_line_width(width as f64);
+            context.stroke()?;
+        }
+    }
+
+    surface.flush();
+    let stride = surface.stride() as usize;
+    let data = surface
+        .data()
+        .expect("Surface should not be borrowed after drawing")
+        .to_vec();
+    // cairo stores ARGB32 in native endianness
+    let format = if cfg!(target_endian = "little") {
+        gdk::MemoryFormat::B8g8r8a8Premultiplied
+    } else {
+        gdk::MemoryFormat::A8r8g8b8Premultiplied
+    };
+    let texture = gdk::MemoryTexture::new(
+        image_width,
+        image_height,
+        format,
+        &glib::Bytes::from_owned(data),
+        stride,
+    );
+    Ok(texture.save_to_png_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let strokes = vec![
+            Stroke::new(vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.5 }]),
+            Stroke::new(vec![Point { x: 5.0, y: 6.0 }]),
+            Stroke::default(),
+        ];
+        let svg = svg(&strokes, 700.0, 400.0, 2.0).unwrap();
+
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"1400.00\" height=\"800.00\" viewBox=\"0 0 700.00 400.00\">"
+        ));
+        assert!(svg.contains("<path d=\"M1.00 2.00 L3.00 4.50\" stroke-width=\"3\"/>"));
+        assert!(svg.contains("<circle cx=\"5.00\" cy=\"6.00\" r=\"1.5\" stroke-width=\"3\"/>"));
+        assert_eq!(
+            svg.matches("<path").count() + svg.matches("<circle").count(),
+            2
+        );
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_image_size() {
+        assert_eq!(image_size(700.0, 400.0, 2.0).unwrap(), (1400, 800));
+        assert_eq!(image_size(700.0, 400.0, 0.001).unwrap(), (1, 1));
+        for scale in [0.0, -1.0, f64::NAN, f64::INFINITY, 1e300] {
+            assert!(matches!(
+                image_size(700.0, 400.0, scale),
+                Err(ExportError::InvalidScale(_))
+            ));
+        }
+        for (width, height) in [(0.0, 400.0), (700.0, 0.0), (f64::NAN, 400.0)] {
+            assert!(matches!(
+                image_size(width, height, 1.0),
+                Err(ExportError::EmptyCanvas)
+            ));
+        }
+    }
+}
@@ -2,6 +2,8 @@ mod application;
 #[rustfmt::skip]
 mod config;
 mod classify;
+mod export;
+mod verify;
 mod widgets;
 mod window;
 
@@ -0,0 +1,192 @@
+//! Checks that symbols compile with a local TeX installation.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use crate::classify::Symbol;
+
+/// Engines used for compiling, in order of preference.
+const ENGINES: [&str; 3] = ["latex", "pdflatex", "lualatex"];
+
+/// Name of the compiled document, without its extension.
+const JOB_NAME: &str = "symbol";
+
+/// Error returned by [`compile`].
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The document could not be written or the engine could not be run.
+    Io(std::io::Error),
+    /// The engine failed, with the error from its log.
+    Compile(String),
+    /// The thread compiling the symbol panicked.
+    Panicked,
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyError::Io(err) => write!(f, "Failed to run LaTeX: {}", err),
+            VerifyError::Compile(err) => write!(f, "{}", err),
+            VerifyError::Panicked => write!(f, "Compiling the symbol panicked"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+impl From<std::io::Error> for VerifyError {
+    fn from(err: std::io::Error) -> Self {
+        VerifyError::Io(err)
+    }
+}
+
+/// Returns the path of the first engine of [`ENGINES`] found on `PATH`,
+/// or `None` if TeX is not installed.
+///
+/// The result is cached, as `PATH` does not change while the app is running.
+pub fn engine() -> Option<&'static Path> {
+    static ENGINE: OnceLock<Option<PathBuf>> = OnceLock::new();
+    ENGINE
+        .get_or_init(|| {
+            let path = std::env::var_os("PATH")?;
+            ENGINES.iter().find_map(|engine| {
+                std::env::split_paths(&path)
+                    .map(|dir| dir.join(engine))
+                    .find(|path| path.is_file())
+            })
+        })
+        .as_deref()
+}
+
+/// Returns a minimal document using the symbol, i.e. its preamble and command.
+///
+/// Symbols which are only available in math mode are placed in inline math.
+pub fn document(symbol: &Symbol) -> String {
+    let preamble = symbol
+        .preamble()
+        .map(|preamble| format!("{}\n", preamble))
+        .unwrap_or_default();
+    let body = if symbol.text_mode {
+        symbol.command.to_owned()
+    } else {
+        format!("${}$", symbol.command)
+    };
+    format!(
+        "\\documentclass{{article}}\n{}\\begin{{document}}\n{}\n\\end{{document}}\n",
+        preamble, body
+    )
+}
+
+/// Compiles the [`document`] of the symbol with the `engine` in a temporary directory.
+///
+/// This blocks until the engine has finished, so it should not be called on the UI thread.
+pub fn compile(engine: &Path, symbol: &Symbol) -> Result<(), VerifyError> {
+    // several symbols can be compiled at the same time
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "hieroglyphic-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    // the directory must not exist yet, so a stale one is never reused and then removed
+    std::fs::create_dir(&dir)?;
+
+    let result = run(engine, symbol, &dir);
+    if let Err(err) = std::fs::remove_dir_all(&dir) {
+        tracing::warn!("Failed to remove {}: {}", dir.display(), err);
+    }
+    result
+}
+
+fn run(engine: &Path, symbol: &Symbol, dir: &Path) -> Result<(), VerifyError> {
+    let tex = format!("{}.tex", JOB_NAME);
+    std::fs::write(dir.join(&tex), document(symbol))?;
+
+    let output = Command::new(engine)
+        .args([
+            "-interaction=nonstopmode",
+            "-halt-on-error",
+            "-no-shell-escape",
+            &tex,
+        ])
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    // the log is not always valid UTF-8, e.g. when reporting invalid characters
+    let log = std::fs::read(dir.join(format!("{}.log", JOB_NAME)))
+        .map(|log| String::from_utf8_lossy(&log).into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(&output.stdout).into_owned());
+    Err(VerifyError::Compile(log_error(&log).unwrap_or_else(|| {
+        format!("LaTeX exited with {}", output.status)
+    })))
+}
+
+/// Returns the first error of a TeX log, e.g. `Undefined control sequence.`,
+/// followed by the line it occurred on, e.g. `l.4 $\foo`.
+fn log_error(log: &str) -> Option<String> {
+    let mut lines = log.lines().skip_while(|line| !line.starts_with("! "));
+    let message = lines.next()?.trim_start_matches("! ").trim();
+    let location = lines
+        .take_while(|line| !line.starts_with("! "))
+        .find(|line| line.starts_with("l."));
+    Some(match location {
+        Some(location) => format!("{}\n{}", message, location.trim_end()),
+        None => message.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document() {
+        let alpha = Symbol::from_id("latex2e-alpha").unwrap();
+        assert_eq!(
+            document(&alpha),
+            "\\documentclass{article}\n\\begin{document}\n$\\alpha$\n\\end{document}\n"
+        );
+
+        let guillemot = Symbol::from_id("latex2e-t1-guillemotleft").unwrap();
+        assert_eq!(
+            document(&guillemot),
+            "\\documentclass{article}\n\\usepackage[T1]{fontenc}\n\\begin{document}\n\\guillemotleft\n\\end{document}\n"
+        );
+    }
+
+    #[test]
+    fn test_log_error() {
+        let log = "\
+(./symbol.tex
+LaTeX2e <2023-11-01>
+! Undefined control sequence.
+l.4 $\\foo
+          $
+No pages of output.";
+        assert_eq!(
+            log_error(log),
+            Some("Undefined control sequence.\nl.4 $\\foo".to_owned())
+        );
+
+        let log = "\
+! LaTeX Error: File `foo.sty' not found.
+
+Type X to quit or <RETURN> to proceed,
+or enter new name. (Default extension: sty)
+
+! Emergency stop.
+<read *>";
+        assert_eq!(
+            log_error(log),
+            Some("LaTeX Error: File `foo.sty' not found.".to_owned())
+        );
+
+        assert_eq!(log_error("Output written on symbol.dvi (1 page)."), None);
+    }
+}
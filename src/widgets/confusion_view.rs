@@ -0,0 +1,103 @@
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+
+use crate::classify;
+
+mod imp {
+    use adw::subclass::bin::BinImpl;
+
+    use super::*;
+
+    #[derive(Default, Debug, gtk::CompositeTemplate)]
+    #[template(resource = "/io/github/finefindus/Hieroglyphic/ui/confusion-view.ui")]
+    pub struct ConfusionView {
+        #[template_child]
+        pub(super) symbols: TemplateChild<gtk::Box>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ConfusionView {
+        const NAME: &'static str = "ConfusionView";
+        type ParentType = adw::Bin;
+        type Type = super::ConfusionView;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ConfusionView {
+        fn dispose(&self) {
+            self.dispose_template();
+        }
+    }
+
+    impl WidgetImpl for ConfusionView {}
+    impl BinImpl for ConfusionView {}
+}
+
+glib::wrapper! {
+    pub struct ConfusionView(ObjectSubclass<imp::ConfusionView>)
+    @extends gtk::Widget, adw::Bin;
+}
+
+impl ConfusionView {
+    /// Shows the given confusable symbols side by side, each labelled with what sets it apart.
+    ///
+    /// The view is hidden if there are no confusable symbols.
+    pub fn set_confusables(&self, confusables: &[(&'static str, &'static str)]) {
+        let symbols = &self.imp().symbols;
+        while let Some(child) = symbols.first_child() {
+            symbols.remove(&child);
+        }
+
+        for &(id, difference) in confusables {
+            let Some(symbol) = classify::Symbol::from_id(id) else {
+                continue;
+            };
+            symbols.append(&Self::symbol_button(id, &symbol, difference));
+        }
+        self.set_visible(symbols.first_child().is_some());
+    }
+
+    /// Creates a button showing a large icon of the symbol, which copies the symbol when clicked.
+    fn symbol_button(id: &str, symbol: &classify::Symbol, difference: &str) -> gtk::Button {
+        let icon = gtk::Image::builder()
+            .icon_name(symbol.icon_name())
+            .pixel_size(64)
+            .build();
+        let command = gtk::Label::builder()
+            .label(symbol.command)
+            .css_classes(["heading"])
+            .build();
+        let difference = gtk::Label::builder()
+            .label(gettext(difference))
+            .wrap(true)
+            .justify(gtk::Justification::Center)
+            .max_width_chars(14)
+            .css_classes(["caption", "dim-label"])
+            .build();
+
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(6)
+            .build();
+        content.append(&icon);
+        content.append(&command);
+        content.append(&difference);
+
+        let button = gtk::Button::builder()
+            .child(&content)
+            .action_name("win.copy-symbol")
+            .css_classes(["flat"])
+            .build();
+        button.set_action_target(Some(id));
+        button
+    }
+}
@@ -0,0 +1,145 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::{gettext, ngettext};
+use gtk::glib;
+
+use crate::classify;
+use crate::widgets::DrawingArea;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Debug, gtk::CompositeTemplate)]
+    #[template(resource = "/io/github/finefindus/Hieroglyphic/ui/custom-symbol-dialog.ui")]
+    pub struct CustomSymbolDialog {
+        #[template_child]
+        pub(super) command_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) package_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) mode_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) examples_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) drawing_area: TemplateChild<DrawingArea>,
+        #[template_child]
+        pub(super) add_button: TemplateChild<gtk::Button>,
+        pub(super) examples: RefCell<Vec<Vec<classify::Stroke>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for CustomSymbolDialog {
+        const NAME: &'static str = "CustomSymbolDialog";
+        type ParentType = adw::Dialog;
+        type Type = super::CustomSymbolDialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_instance_callbacks();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for CustomSymbolDialog {}
+
+    impl WidgetImpl for CustomSymbolDialog {}
+    impl AdwDialogImpl for CustomSymbolDialog {}
+}
+
+glib::wrapper! {
+    pub struct CustomSymbolDialog(ObjectSubclass<imp::CustomSymbolDialog>)
+    @extends gtk::Widget, adw::Dialog;
+}
+
+#[gtk::template_callbacks]
+impl CustomSymbolDialog {
+    /// Creates a new dialog, using the `drawing` as the first example if it is not empty.
+    pub fn new(drawing: Vec<classify::Stroke>) -> Self {
+        let dialog: Self = glib::Object::new();
+        if !drawing.is_empty() {
+            dialog.imp().examples.borrow_mut().push(drawing);
+        }
+        dialog.update();
+        dialog
+    }
+
+    /// Updates the number of examples and whether the symbol can be added.
+    fn update(&self) {
+        let imp = self.imp();
+        let examples = imp.examples.borrow().len();
+        let description = if examples == 0 {
+            gettext("Draw the symbol a few times, so it can be recognized by drawing")
+        } else {
+            ngettext("{} example", "{} examples", examples as u32)
+                .replace("{}", &examples.to_string())
+        };
+        imp.examples_group.set_description(Some(&description));
+        // the current drawing is added as an example as well
+        let has_examples = examples > 0 || !imp.drawing_area.strokes().is_empty();
+        imp.add_button
+            .set_sensitive(!imp.command_row.text().trim().is_empty() && has_examples);
+    }
+
+    #[template_callback]
+    fn on_changed(&self) {
+        self.update();
+    }
+
+    /// Adds the current drawing as an example and clears it, so the next one can be drawn.
+    #[template_callback]
+    fn on_add_example(&self) {
+        let strokes = self.imp().drawing_area.strokes();
+        if strokes.is_empty() {
+            return;
+        }
+        self.imp().examples.borrow_mut().push(strokes);
+        self.imp().drawing_area.clear();
+        self.update();
+    }
+
+    #[template_callback]
+    fn on_clear(&self) {
+        self.imp().drawing_area.clear();
+        self.update();
+    }
+
+    #[template_callback]
+    fn on_cancel(&self) {
+        self.close();
+    }
+
+    /// Registers the custom symbol, replacing one with the same id,
+    /// and lets the window store it.
+    #[template_callback]
+    fn on_add(&self) {
+        let imp = self.imp();
+        self.on_add_example();
+
+        let package = imp.package_row.text().trim().to_owned();
+        let (math_mode, text_mode) = match imp.mode_row.selected() {
+            0 => (true, false),
+            1 => (false, true),
+            _ => (true, true),
+        };
+        let symbol = classify::CustomSymbol {
+            command: imp.command_row.text().trim().to_owned(),
+            package: (!package.is_empty()).then_some(package),
+            text_mode,
+            math_mode,
+            examples: imp.examples.take(),
+        };
+
+        let mut symbols = classify::custom_symbols();
+        symbols.retain(|other| other.id() != symbol.id());
+        symbols.push(symbol);
+        classify::set_custom_symbols(symbols);
+
+        let _ = self.activate_action("win.save-custom-symbols", None);
+        self.close();
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
-use gtk::{glib, gsk};
+use gtk::{gdk, glib, gsk};
+use itertools::Itertools;
 
 use crate::classify;
 
@@ -9,24 +13,89 @@ use crate::classify;
 #[boxed_type(name = "BoxedStrokes")]
 pub struct BoxedStrokes(pub Vec<classify::Stroke>);
 
+/// Interval in which new points are added during a replay.
+const REPLAY_INTERVAL: Duration = Duration::from_millis(16);
+/// Width of the drawn lines.
+const LINE_WIDTH: f32 = 3.0;
+/// Color of the drawn lines in light mode (adw `@dark_2`), which is also used for exports.
+pub const LINE_COLOR: gdk::RGBA = gdk::RGBA::new(0.37, 0.36, 0.39, 1.0);
+/// Color of the drawn lines in dark mode (#CCCCCC).
+const DARK_LINE_COLOR: gdk::RGBA = gdk::RGBA::new(0.8, 0.8, 0.8, 1.0);
+
+/// Part of a stroke, which is drawn as a single line with round caps and joins.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StrokePart {
+    /// A single point, drawn as a circle with a radius of half the line width.
+    Dot(classify::Point, f32),
+    /// A line through the points with the given width.
+    Line(Vec<classify::Point>, f32),
+}
+
+/// Returns the parts the stroke is drawn as, which are shared by the drawing area and
+/// the exported images, so both look the same.
+///
+/// Strokes drawn with pressure are split into one line per segment, with the width
+/// depending on the pressure at its end.
+pub fn stroke_parts(stroke: &classify::Stroke) -> Vec<StrokePart> {
+    let points = stroke.points().copied().collect_vec();
+    // strokes without details, e.g. from older recordings, are drawn without pressure
+    let pressures = stroke
+        .details()
+        .map(|details| details.pressure)
+        .chain(std::iter::repeat(None))
+        .take(points.len())
+        .collect_vec();
+
+    match points[..] {
+        [] => Vec::new(),
+        [point] => vec![StrokePart::Dot(point, line_width(pressures[0]))],
+        _ if pressures.iter().any(Option::is_some) => points
+            .into_iter()
+            .zip(pressures)
+            .tuple_windows()
+            .map(|((p, _), (q, pressure))| StrokePart::Line(vec![p, q], line_width(pressure)))
+            .collect(),
+        _ => vec![StrokePart::Line(points, LINE_WIDTH)],
+    }
+}
+
+/// Returns the width of a line drawn with the given `pressure`.
+///
+/// A medium pressure results in the same width as drawing with a mouse.
+fn line_width(pressure: Option<f64>) -> f32 {
+    pressure.map_or(LINE_WIDTH, |pressure| {
+        LINE_WIDTH * (0.25 + 1.5 * pressure.clamp(0.0, 1.0) as f32)
+    })
+}
+
 mod imp {
     use adw::subclass::bin::BinImpl;
     use glib::subclass::Signal;
-    use gtk::gdk;
-    use itertools::Itertools;
 
     use crate::classify;
 
     use super::*;
-    use std::{cell::RefCell, sync::OnceLock};
+    use std::{
+        cell::{Cell, RefCell},
+        sync::OnceLock,
+    };
 
-    #[derive(Default, Debug, gtk::CompositeTemplate)]
+    #[derive(Default, Debug, gtk::CompositeTemplate, glib::Properties)]
     #[template(resource = "/io/github/finefindus/Hieroglyphic/ui/drawing-area.ui")]
+    #[properties(wrapper_type = super::DrawingArea)]
     pub struct DrawingArea {
+        /// Whether to show the baseline, x-height and centre guides.
+        #[property(get, set)]
+        show_guides: Cell<bool>,
         #[template_child]
         drag: TemplateChild<gtk::GestureDrag>,
+        #[template_child]
+        stylus: TemplateChild<gtk::GestureStylus>,
         pub(super) strokes: RefCell<Vec<classify::Stroke>>,
         pub(super) current_stroke: RefCell<classify::Stroke>,
+        /// Time when the first point of the drawing was added.
+        pub(super) started: Cell<Option<Instant>>,
+        pub(super) replay_source: RefCell<Option<glib::SourceId>>,
     }
 
     #[glib::object_subclass]
@@ -45,6 +114,7 @@ mod imp {
         }
     }
 
+    #[glib::derived_properties]
     impl ObjectImpl for DrawingArea {
         fn signals() -> &'static [Signal] {
             static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
@@ -57,7 +127,11 @@ mod imp {
 
         fn constructed(&self) {
             self.parent_constructed();
+            // the stylus gesture has to come first, so it can claim stylus input before the drag
+            self.obj().add_controller(self.stylus.get());
             self.obj().add_controller(self.drag.get());
+            self.obj()
+                .connect_show_guides_notify(|area| area.queue_draw());
         }
     }
 
@@ -65,6 +139,9 @@ mod imp {
         fn snapshot(&self, snapshot: &gtk::Snapshot) {
             self.parent_snapshot(snapshot);
             let color = self.line_color();
+            if self.show_guides.get() {
+                self.append_guides(snapshot, &color);
+            }
 
             let curr_stroke = self.current_stroke.borrow().clone();
             for stroke in self
@@ -75,33 +152,45 @@ mod imp {
             {
                 tracing::trace!("Drawing: {:?}", stroke);
 
-                let path_builder = gsk::PathBuilder::new();
-                for (p, q) in stroke.points().tuple_windows() {
-                    path_builder.move_to(p.x as f32, p.y as f32);
-                    path_builder.line_to(q.x as f32, q.y as f32);
-                }
-
-                if stroke.points().count() == 1 {
-                    let point = stroke.points().next().unwrap();
-                    path_builder.add_circle(
-                        &gtk::graphene::Point::new(point.x as f32, point.y as f32),
-                        1.5,
-                    );
+                for part in stroke_parts(stroke) {
+                    let path_builder = gsk::PathBuilder::new();
+                    let width = match part {
+                        StrokePart::Dot(point, width) => {
+                            path_builder.add_circle(
+                                &gtk::graphene::Point::new(point.x as f32, point.y as f32),
+                                width / 2.0,
+                            );
+                            width
+                        }
+                        StrokePart::Line(points, width) => {
+                            if let Some((first, rest)) = points.split_first() {
+                                path_builder.move_to(first.x as f32, first.y as f32);
+                                for point in rest {
+                                    path_builder.line_to(point.x as f32, point.y as f32);
+                                }
+                            }
+                            width
+                        }
+                    };
+                    append_path(snapshot, &path_builder.to_path(), width, &color);
                 }
-
-                let path = path_builder.to_path();
-                let stroke = gsk::Stroke::new(3.0);
-                stroke.set_line_cap(gsk::LineCap::Round);
-                let Some(bounds) = path.stroke_bounds(&stroke) else {
-                    continue;
-                };
-                snapshot.push_stroke(&path, &stroke);
-                snapshot.append_color(&color, &bounds);
-                snapshot.pop();
             }
         }
     }
 
+    /// Appends the `path` as a line with the given `width` and `color` to the snapshot.
+    fn append_path(snapshot: &gtk::Snapshot, path: &gsk::Path, width: f32, color: &gdk::RGBA) {
+        let stroke = gsk::Stroke::new(width);
+        stroke.set_line_cap(gsk::LineCap::Round);
+        stroke.set_line_join(gsk::LineJoin::Round);
+        let Some(bounds) = path.stroke_bounds(&stroke) else {
+            return;
+        };
+        snapshot.push_stroke(path, &stroke);
+        snapshot.append_color(color, &bounds);
+        snapshot.pop();
+    }
+
     impl BinImpl for DrawingArea {}
 
     #[gtk::template_callbacks]
@@ -109,50 +198,148 @@ mod imp {
         /// Returns a theme-specific color for the drawing line.
         fn line_color(&self) -> gdk::RGBA {
             if adw::StyleManager::default().is_dark() {
-                // #CCCCCC
-                gdk::RGBA::new(0.8, 0.8, 0.8, 1.0)
+                DARK_LINE_COLOR
             } else {
-                // adw @dark_2 color
-                gdk::RGBA::new(0.37, 0.36, 0.39, 1.0)
+                LINE_COLOR
             }
         }
 
-        #[template_callback]
-        fn on_drag_begin(&self, x: f64, y: f64) {
-            tracing::trace!("Drag started at {},{}", x, y);
+        /// Draws the guide lines, which help to place symbols that mainly differ
+        /// in their vertical position.
+        fn append_guides(&self, snapshot: &gtk::Snapshot, color: &gdk::RGBA) {
+            let width = self.obj().width() as f32;
+            let height = self.obj().height() as f32;
+            let x_height = classify::X_HEIGHT as f32 * height;
+            let baseline = classify::BASELINE as f32 * height;
+
+            let guides = [
+                // centre grid
+                ((width / 2.0, 0.0), (width / 2.0, height), 0.1),
+                ((0.0, height / 2.0), (width, height / 2.0), 0.1),
+                ((0.0, x_height), (width, x_height), 0.25),
+                ((0.0, baseline), (width, baseline), 0.5),
+            ];
+            for ((x0, y0), (x1, y1), alpha) in guides {
+                let path_builder = gsk::PathBuilder::new();
+                path_builder.move_to(x0, y0);
+                path_builder.line_to(x1, y1);
+                append_path(
+                    snapshot,
+                    &path_builder.to_path(),
+                    1.0,
+                    &gdk::RGBA::new(color.red(), color.green(), color.blue(), alpha),
+                );
+            }
+        }
+
+        /// Returns the time in milliseconds since the start of the drawing.
+        fn elapsed(&self) -> u64 {
+            let started = self.started.get().unwrap_or_else(Instant::now);
+            self.started.set(Some(started));
+            started.elapsed().as_millis() as u64
+        }
+
+        /// Adds a point with the given details to the current stroke.
+        ///
+        /// If no details are given, only the time since the start of the drawing is recorded.
+        pub(super) fn add_point(
+            &self,
+            point: classify::Point,
+            details: Option<classify::PointDetails>,
+        ) {
+            let details = details.unwrap_or_else(|| classify::PointDetails {
+                time: self.elapsed(),
+                ..Default::default()
+            });
             self.current_stroke
                 .borrow_mut()
-                .add_point(classify::Point { x, y });
+                .add_point_with_details(point, details);
             self.obj().queue_draw();
         }
 
+        /// Returns the details of the current stylus event, including pressure and tilt
+        /// if supported by the device.
+        fn stylus_details(&self) -> classify::PointDetails {
+            let tilt = self
+                .stylus
+                .axis(gdk::AxisUse::Xtilt)
+                .zip(self.stylus.axis(gdk::AxisUse::Ytilt));
+            classify::PointDetails {
+                time: self.elapsed(),
+                pressure: self.stylus.axis(gdk::AxisUse::Pressure),
+                tilt,
+            }
+        }
+
+        /// Finishes the current stroke and notifies listeners about the new strokes.
+        pub(super) fn finish_stroke(&self) {
+            let stroke = self.current_stroke.take();
+            self.strokes.borrow_mut().push(stroke);
+            self.obj().queue_draw();
+
+            self.obj().emit_by_name(
+                "stroke-drawn",
+                &[&BoxedStrokes(self.strokes.borrow().clone())],
+            )
+        }
+
+        #[template_callback]
+        fn on_drag_begin(&self, x: f64, y: f64) {
+            tracing::trace!("Drag started at {},{}", x, y);
+            self.add_point(classify::Point { x, y }, None);
+        }
+
         #[template_callback]
         fn on_drag_update(&self, x: f64, y: f64) {
             tracing::trace!("Drag update at {},{}", x, y);
-            let mut stroke = self.current_stroke.borrow_mut();
             // x,y refers to movements relative to start coord
-            let &classify::Point {
+            let Some(&classify::Point {
                 x: prev_x,
                 y: prev_y,
-            } = stroke.points().next().unwrap();
-            stroke.add_point(classify::Point {
-                x: prev_x + x,
-                y: prev_y + y,
-            });
-            self.obj().queue_draw();
+            }) = self.current_stroke.borrow().points().next()
+            else {
+                return;
+            };
+            self.add_point(
+                classify::Point {
+                    x: prev_x + x,
+                    y: prev_y + y,
+                },
+                None,
+            );
         }
 
         #[template_callback]
         fn on_drag_end(&self, x: f64, y: f64) {
             tracing::trace!("Drag end at {},{}", x, y);
-            let stroke = self.current_stroke.take();
-            self.strokes.borrow_mut().push(stroke);
-            self.obj().queue_draw();
+            self.finish_stroke();
+        }
 
-            self.obj().emit_by_name(
-                "stroke-drawn",
-                &[&BoxedStrokes(self.strokes.borrow().clone())],
-            )
+        #[template_callback]
+        fn on_stylus_down(&self, x: f64, y: f64) {
+            tracing::trace!("Stylus down at {},{}", x, y);
+            // prevent the drag gesture from handling the same input
+            self.stylus.set_state(gtk::EventSequenceState::Claimed);
+            self.current_stroke.borrow_mut().clear();
+            self.add_point(classify::Point { x, y }, Some(self.stylus_details()));
+        }
+
+        #[template_callback]
+        fn on_stylus_motion(&self, x: f64, y: f64) {
+            if self.current_stroke.borrow().points().next().is_none() {
+                return;
+            }
+            tracing::trace!("Stylus motion at {},{}", x, y);
+            self.add_point(classify::Point { x, y }, Some(self.stylus_details()));
+        }
+
+        #[template_callback]
+        fn on_stylus_up(&self, x: f64, y: f64) {
+            tracing::trace!("Stylus up at {},{}", x, y);
+            if self.current_stroke.borrow().points().next().is_none() {
+                return;
+            }
+            self.finish_stroke();
         }
     }
 }
@@ -170,7 +357,83 @@ impl DrawingArea {
         //clear previous strokes
         self.imp().strokes.borrow_mut().clear();
         self.imp().current_stroke.borrow_mut().clear();
+        self.imp().started.set(None);
+        if let Some(source) = self.imp().replay_source.take() {
+            source.remove();
+        }
 
         self.queue_draw();
     }
+
+    /// Returns the strokes of the current drawing.
+    pub fn strokes(&self) -> Vec<classify::Stroke> {
+        self.imp().strokes.borrow().clone()
+    }
+
+    /// Returns a recording of the current drawing, including the stylus data.
+    pub fn recording(&self) -> classify::Recording {
+        classify::Recording::new(
+            self.width() as f64,
+            self.height() as f64,
+            &self.imp().strokes.borrow(),
+        )
+    }
+
+    /// Replays the given recording with its original timing, as if it was drawn by the user.
+    ///
+    /// The drawing is scaled to the current size of the canvas.
+    ///
+    /// `stroke-drawn` is emitted after each stroke, so the classification
+    /// is exactly the same as for the original drawing.
+    pub fn replay(&self, recording: classify::Recording) {
+        self.clear();
+        let start = Instant::now();
+        self.imp().started.set(Some(start));
+
+        let mut strokes: VecDeque<VecDeque<_>> = recording
+            .strokes_on_canvas(self.width() as f64, self.height() as f64)
+            .into_iter()
+            .map(|stroke| {
+                stroke
+                    .points()
+                    .copied()
+                    .zip(stroke.details().copied())
+                    .collect::<VecDeque<_>>()
+            })
+            .filter(|stroke| !stroke.is_empty())
+            .collect();
+
+        let source = glib::timeout_add_local(
+            REPLAY_INTERVAL,
+            glib::clone!(
+                #[weak(rename_to = area)]
+                self,
+                #[upgrade_or]
+                glib::ControlFlow::Break,
+                move || {
+                    let elapsed = start.elapsed().as_millis() as u64;
+                    while let Some(stroke) = strokes.front_mut() {
+                        while let Some((point, details)) = stroke
+                            .front()
+                            .copied()
+                            .filter(|(_, details)| details.time <= elapsed)
+                        {
+                            stroke.pop_front();
+                            area.imp().add_point(point, Some(details));
+                        }
+                        if !stroke.is_empty() {
+                            return glib::ControlFlow::Continue;
+                        }
+                        strokes.pop_front();
+                        area.imp().finish_stroke();
+                    }
+
+                    tracing::debug!("Replay finished");
+                    area.imp().replay_source.take();
+                    glib::ControlFlow::Break
+                }
+            ),
+        );
+        self.imp().replay_source.replace(Some(source));
+    }
 }
@@ -1,9 +1,18 @@
 pub mod about;
+mod confusion_view;
+mod custom_symbol_dialog;
 mod drawing_area;
 mod indicator_button;
+mod symbol_catalogue;
+mod symbol_details;
 mod symbol_item;
 
+pub use confusion_view::ConfusionView;
+pub use custom_symbol_dialog::CustomSymbolDialog;
 pub use drawing_area::BoxedStrokes;
 pub use drawing_area::DrawingArea;
+pub use drawing_area::{stroke_parts, StrokePart, LINE_COLOR};
 pub use indicator_button::IndicatorButton;
+pub use symbol_catalogue::SymbolCatalogue;
+pub use symbol_details::SymbolDetails;
 pub use symbol_item::SymbolItem;
@@ -0,0 +1,105 @@
+use std::cell::Cell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::{gettext, ngettext};
+use gtk::glib;
+
+use crate::classify;
+use crate::widgets::SymbolItem;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Debug, gtk::CompositeTemplate)]
+    #[template(resource = "/io/github/finefindus/Hieroglyphic/ui/symbol-catalogue.ui")]
+    pub struct SymbolCatalogue {
+        #[template_child]
+        pub(super) tables_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) packages_group: TemplateChild<adw::PreferencesGroup>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SymbolCatalogue {
+        const NAME: &'static str = "SymbolCatalogue";
+        type ParentType = adw::Dialog;
+        type Type = super::SymbolCatalogue;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SymbolCatalogue {
+        fn constructed(&self) {
+            self.parent_constructed();
+            for (table, symbols) in classify::symbols_by_table() {
+                self.tables_group
+                    .add(&super::SymbolCatalogue::group_row(&gettext(table), symbols));
+            }
+            for (package, symbols) in classify::symbols_by_package() {
+                self.packages_group
+                    .add(&super::SymbolCatalogue::group_row(package, symbols));
+            }
+        }
+
+        fn dispose(&self) {
+            self.dispose_template();
+        }
+    }
+
+    impl WidgetImpl for SymbolCatalogue {}
+    impl AdwDialogImpl for SymbolCatalogue {}
+}
+
+glib::wrapper! {
+    pub struct SymbolCatalogue(ObjectSubclass<imp::SymbolCatalogue>)
+    @extends gtk::Widget, adw::Dialog;
+}
+
+impl SymbolCatalogue {
+    pub fn new() -> Self {
+        glib::Object::new()
+    }
+
+    /// Creates an expandable row listing the given symbols, which copies a symbol when it
+    /// is activated.
+    fn group_row(title: &str, symbols: Vec<&'static classify::Symbol>) -> adw::ExpanderRow {
+        let row = adw::ExpanderRow::builder()
+            .title(title)
+            .subtitle(
+                ngettext("{} symbol", "{} symbols", symbols.len() as u32)
+                    .replace("{}", &symbols.len().to_string()),
+            )
+            .build();
+        row.set_use_markup(false);
+
+        // creating all symbols at once takes too long, so only create them when needed
+        let populated = Cell::new(false);
+        row.connect_expanded_notify(move |row| {
+            if !row.is_expanded() || populated.replace(true) {
+                return;
+            }
+            for &symbol in &symbols {
+                let item = gtk::ListBoxRow::builder()
+                    .child(&SymbolItem::new(symbol.clone()))
+                    .action_name("win.copy-symbol")
+                    .build();
+                item.set_action_target(Some(symbol.id()));
+                row.add_row(&item);
+            }
+        });
+        row
+    }
+}
+
+impl Default for SymbolCatalogue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
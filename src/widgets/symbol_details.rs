@@ -0,0 +1,214 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gtk::glib;
+
+use crate::classify;
+
+/// Maximal number of related symbols that are shown.
+const MAX_RELATED: usize = 48;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Debug, gtk::CompositeTemplate)]
+    #[template(resource = "/io/github/finefindus/Hieroglyphic/ui/symbol-details.ui")]
+    pub struct SymbolDetails {
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
+        #[template_child]
+        pub(super) image: TemplateChild<gtk::Image>,
+        #[template_child]
+        pub(super) command_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) aliases_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) package_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) encoding_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) preamble_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) mode_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) unicode_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) copy_row: TemplateChild<adw::ButtonRow>,
+        #[template_child]
+        pub(super) remove_row: TemplateChild<adw::ButtonRow>,
+        #[template_child]
+        pub(super) related_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) related_box: TemplateChild<gtk::FlowBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SymbolDetails {
+        const NAME: &'static str = "SymbolDetails";
+        type ParentType = adw::Dialog;
+        type Type = super::SymbolDetails;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(
+                "details.show",
+                Some(glib::VariantTy::STRING),
+                |dialog, _, id| {
+                    if let Some(symbol) = id
+                        .and_then(|id| id.str())
+                        .and_then(classify::Symbol::from_id)
+                    {
+                        dialog.set_symbol(&symbol);
+                    }
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SymbolDetails {
+        fn constructed(&self) {
+            self.parent_constructed();
+            let obj = self.obj();
+            for row in [
+                &*self.command_row,
+                &*self.aliases_row,
+                &*self.package_row,
+                &*self.encoding_row,
+                &*self.preamble_row,
+                &*self.mode_row,
+                &*self.unicode_row,
+            ] {
+                obj.add_copy_button(row);
+            }
+            self.copy_row.connect_activated(glib::clone!(
+                #[weak]
+                obj,
+                move |_| {
+                    obj.copy_with_preamble();
+                }
+            ));
+            // the symbol no longer exists after removing it
+            self.remove_row.connect_activated(glib::clone!(
+                #[weak]
+                obj,
+                move |_| {
+                    obj.close();
+                }
+            ));
+        }
+
+        fn dispose(&self) {
+            self.dispose_template();
+        }
+    }
+
+    impl WidgetImpl for SymbolDetails {}
+    impl AdwDialogImpl for SymbolDetails {}
+}
+
+glib::wrapper! {
+    pub struct SymbolDetails(ObjectSubclass<imp::SymbolDetails>)
+    @extends gtk::Widget, adw::Dialog;
+}
+
+impl SymbolDetails {
+    pub fn new(symbol: &classify::Symbol) -> Self {
+        let dialog: Self = glib::Object::new();
+        dialog.set_symbol(symbol);
+        dialog
+    }
+
+    /// Shows the details of the given symbol.
+    pub fn set_symbol(&self, symbol: &classify::Symbol) {
+        let imp = self.imp();
+        imp.image.set_icon_name(Some(&symbol.icon_name()));
+        imp.command_row.set_subtitle(symbol.command);
+        let alternatives = symbol.alternatives();
+        imp.aliases_row.set_subtitle(&alternatives.join(", "));
+        imp.aliases_row.set_visible(!alternatives.is_empty());
+        imp.package_row.set_subtitle(symbol.package);
+        imp.mode_row.set_subtitle(symbol.mode());
+
+        imp.encoding_row.set_subtitle(symbol.font_encoding);
+        imp.encoding_row.set_visible(!symbol.is_custom());
+
+        let preamble = symbol.preamble();
+        imp.preamble_row
+            .set_subtitle(preamble.as_deref().unwrap_or_default());
+        imp.preamble_row.set_visible(preamble.is_some());
+        imp.copy_row.set_visible(preamble.is_some());
+
+        let unicode = symbol
+            .unicode()
+            .map(|c| format!("{} (U+{:04X})", c, c as u32));
+        imp.unicode_row
+            .set_subtitle(unicode.as_deref().unwrap_or_default());
+        imp.unicode_row.set_visible(unicode.is_some());
+
+        imp.remove_row.set_action_target(Some(symbol.id()));
+        imp.remove_row.set_visible(symbol.is_custom());
+
+        imp.related_box.remove_all();
+        let related = symbol.related();
+        for related in related.iter().take(MAX_RELATED) {
+            imp.related_box.append(&Self::related_button(related));
+        }
+        imp.related_group.set_visible(!related.is_empty());
+    }
+
+    /// Adds a button to the row, which copies its subtitle.
+    fn add_copy_button(&self, row: &adw::ActionRow) {
+        let button = gtk::Button::builder()
+            .icon_name("edit-copy-symbolic")
+            .tooltip_text(gettext("Copy"))
+            .valign(gtk::Align::Center)
+            .css_classes(["flat"])
+            .build();
+        button.connect_clicked(glib::clone!(
+            #[weak(rename_to = dialog)]
+            self,
+            #[weak]
+            row,
+            move |_| {
+                let text = row.subtitle().unwrap_or_default();
+                dialog.clipboard().set_text(&text);
+                dialog.show_toast(&gettext("Copied “{}”").replace("{}", &text));
+            }
+        ));
+        row.add_suffix(&button);
+    }
+
+    /// Copies the preamble followed by the command, so they can be pasted into
+    /// a new document.
+    fn copy_with_preamble(&self) {
+        let imp = self.imp();
+        let command = imp.command_row.subtitle().unwrap_or_default();
+        let preamble = imp.preamble_row.subtitle().unwrap_or_default();
+        self.clipboard()
+            .set_text(&format!("{}\n{}", preamble, command));
+        self.show_toast(&gettext("Copied “{}” with its preamble").replace("{}", &command));
+    }
+
+    fn show_toast(&self, text: &str) {
+        let toast = adw::Toast::new(text);
+        toast.set_use_markup(false);
+        self.imp().toast_overlay.add_toast(toast);
+    }
+
+    /// Creates a button for a related symbol, which shows its details when clicked.
+    fn related_button(symbol: &classify::Symbol) -> gtk::Button {
+        let button = gtk::Button::builder()
+            .icon_name(symbol.icon_name())
+            .tooltip_text(symbol.command)
+            .action_name("details.show")
+            .css_classes(["flat"])
+            .build();
+        button.set_action_target(Some(symbol.id()));
+        button
+    }
+}
@@ -1,8 +1,13 @@
+use gettextrs::gettext;
 use glib::Object;
 use gtk::subclass::prelude::*;
-use gtk::{glib, prelude::ObjectExt};
+use gtk::{
+    gio, glib,
+    prelude::{AccessibleExtManual, ActionableExt, ObjectExt, WidgetExt},
+};
 
 use crate::classify;
+use crate::verify;
 
 mod imp {
 
@@ -24,6 +29,18 @@ mod imp {
         pub(super) command: RefCell<String>,
         #[property(get, set)]
         pub(super) mode: RefCell<String>,
+        #[property(get, set)]
+        pub(super) aliases: RefCell<String>,
+        #[template_child]
+        pub(super) details_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) aliases_label: TemplateChild<gtk::Inscription>,
+        #[template_child]
+        pub(super) unrecognizable_label: TemplateChild<gtk::Inscription>,
+        #[template_child]
+        pub(super) verify_label: TemplateChild<gtk::Inscription>,
+        #[template_child]
+        pub(super) verify_stack: TemplateChild<gtk::Stack>,
     }
 
     #[glib::object_subclass]
@@ -35,6 +52,10 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
             klass.bind_template_instance_callbacks();
+
+            klass.install_action("item.verify", None, |item, _, _| {
+                item.verify();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -66,27 +87,97 @@ glib::wrapper! {
 #[gtk::template_callbacks]
 impl SymbolItem {
     pub fn new(symbol: classify::Symbol) -> Self {
-        Object::builder()
+        let alternatives = symbol.alternatives();
+        let item: Self = Object::builder()
             .property("id", symbol.id())
-            .property(
-                "icon",
-                // icon file names do not contain ending '='
-                format!("{}-symbolic", symbol.id().trim_end_matches('=')),
-            )
+            .property("icon", symbol.icon_name())
             .property("command", symbol.command)
             .property("package", symbol.package)
+            .property("mode", symbol.mode())
             .property(
-                "mode",
-                match (symbol.math_mode, symbol.text_mode) {
-                    (true, true) => "mathmode & textmode",
-                    (false, true) => "textmode",
-                    (true, false) => "mathmode",
-                    (false, false) => {
-                        // a symbol has to be either math mode or textmode
-                        unreachable!("Symbol {} is neither math nor textmode", symbol.id())
-                    }
-                },
+                "aliases",
+                gettext("Also {}").replace("{}", &alternatives.join(", ")),
             )
-            .build()
+            .build();
+        item.imp()
+            .details_button
+            .set_action_target(Some(symbol.id()));
+        item.imp()
+            .aliases_label
+            .set_visible(!alternatives.is_empty());
+        item.imp()
+            .unrecognizable_label
+            .set_visible(!symbol.is_recognizable());
+        // compiling is only offered if TeX is installed locally, custom symbols may need
+        // definitions from the user's own preamble
+        item.imp()
+            .verify_stack
+            .set_visible(verify::engine().is_some() && !symbol.is_custom());
+        // let screen readers announce the name of the symbol, rather than spelling out its command
+        let label = symbol
+            .description
+            .map(gettext)
+            .unwrap_or_else(|| symbol.command.to_owned());
+        item.update_property(&[
+            gtk::accessible::Property::Label(&label),
+            gtk::accessible::Property::Description(&format!(
+                "{} ({})",
+                symbol.command, symbol.package
+            )),
+        ]);
+        item
+    }
+
+    /// Compiles the symbol with the local TeX installation and shows whether it succeeded.
+    fn verify(&self) {
+        let (Some(engine), Some(symbol)) = (
+            verify::engine(),
+            classify::Symbol::from_id(&self.id()).filter(|symbol| !symbol.is_custom()),
+        ) else {
+            return;
+        };
+        let imp = self.imp();
+        imp.verify_stack.set_visible_child_name("running");
+        imp.verify_label.set_visible(false);
+
+        glib::spawn_future_local(glib::clone!(
+            #[weak(rename_to = item)]
+            self,
+            async move {
+                let result = gio::spawn_blocking(move || verify::compile(engine, &symbol))
+                    .await
+                    .unwrap_or(Err(verify::VerifyError::Panicked));
+                item.show_verification(engine, result);
+            }
+        ));
+    }
+
+    /// Shows the `result` of compiling the symbol with the `engine` below it.
+    fn show_verification(&self, engine: &std::path::Path, result: Result<(), verify::VerifyError>) {
+        let imp = self.imp();
+        imp.verify_stack.set_visible_child_name("verify");
+
+        let engine = engine
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let (text, tooltip, class) = match result {
+            Ok(()) => {
+                let text = gettext("Compiles with {}").replace("{}", &engine);
+                (text, None, "success")
+            }
+            Err(err) => {
+                tracing::debug!("Failed to compile {}: {}", self.command(), err);
+                let text = gettext("Fails with {}").replace("{}", &engine);
+                let err = err.to_string();
+                // the inscription only has room for the first line of the error
+                let first_line = err.lines().next().unwrap_or_default().to_owned();
+                (format!("{}: {}", text, first_line), Some(err), "error")
+            }
+        };
+        imp.verify_label.set_text(Some(&text));
+        imp.verify_label.set_tooltip_text(tooltip.as_deref());
+        imp.verify_label.set_css_classes(&["caption", class]);
+        imp.verify_label.set_visible(true);
     }
 }
@@ -1,22 +1,47 @@
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, SendError};
+use std::time::{Instant, SystemTime};
 
 use adw::prelude::*;
 use gettextrs::gettext;
-use gtk::glib;
 use gtk::subclass::prelude::*;
+use gtk::{gdk, glib};
 
 use crate::application::HieroglyphicApplication;
-use crate::widgets::{BoxedStrokes, SymbolItem};
-use crate::{classify, config};
+use crate::widgets::{
+    BoxedStrokes, ConfusionView, CustomSymbolDialog, SymbolCatalogue, SymbolDetails, SymbolItem,
+};
+use crate::{classify, config, export};
 
 // GTK is single-threaded
 thread_local! {
     static SETTINGS: gio::Settings = gio::Settings::new(config::APP_ID);
 }
 
+/// Input for the classifier thread.
+#[derive(Debug)]
+pub enum ClassifierInput {
+    /// Strokes drawn by the user, with their position relative to the canvas guides,
+    /// if they are shown.
+    Strokes(Vec<classify::Stroke>, Option<f64>),
+    /// An image, e.g. from a file or the clipboard.
+    Image(classify::Image),
+    /// Strokes of multiple symbols, written from left to right.
+    Formula(Vec<classify::Stroke>),
+}
+
+/// Successful result of the classifier thread.
+#[derive(Debug)]
+pub enum ClassifierOutput {
+    /// Most likely symbols for a single symbol.
+    Symbols(Vec<&'static str>),
+    /// Most likely symbol for each symbol of a formula.
+    Formula(Vec<&'static str>),
+}
+
 mod imp {
     use std::{
-        cell::{OnceCell, RefCell},
+        cell::{Cell, OnceCell, RefCell},
         sync::mpsc::Sender,
     };
 
@@ -42,10 +67,25 @@ mod imp {
         pub stack: TemplateChild<gtk::Stack>,
         #[template_child]
         pub indicator_button: TemplateChild<IndicatorButton>,
+        #[template_child]
+        pub formula_banner: TemplateChild<adw::Banner>,
+        #[template_child]
+        pub split_view: TemplateChild<adw::OverlaySplitView>,
+        #[template_child]
+        pub confusion_view: TemplateChild<ConfusionView>,
+        #[template_child]
+        pub search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub search_entry: TemplateChild<gtk::SearchEntry>,
         pub toast: RefCell<Option<adw::Toast>>,
         pub symbols: OnceCell<gio::ListStore>,
         pub symbol_strokes: RefCell<Option<Vec<classify::Stroke>>>,
-        pub classifier: OnceCell<Sender<Vec<classify::Stroke>>>,
+        /// Results of the last classification, shown again when the search is cleared.
+        pub classifications: RefCell<Vec<&'static str>>,
+        pub classifier: RefCell<Option<Sender<ClassifierInput>>>,
+        /// Whether the stored custom symbols could not be loaded, so the file must not be
+        /// replaced without moving it aside first.
+        pub custom_symbols_unreadable: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -77,6 +117,92 @@ mod imp {
             klass.install_action("win.clear", None, move |win, _, _| {
                 win.imp().drawing_area.clear();
             });
+
+            klass.install_action("win.search", None, move |win, _, _| {
+                let imp = win.imp();
+                imp.split_view.set_show_sidebar(true);
+                imp.search_bar.set_search_mode(true);
+                imp.search_entry.grab_focus();
+            });
+
+            klass.install_action(
+                "win.copy-symbol",
+                Some(glib::VariantTy::STRING),
+                move |win, _, id| {
+                    if let Some(id) = id.and_then(|id| id.str()) {
+                        win.copy_symbol(id);
+                    }
+                },
+            );
+
+            klass.install_action(
+                "win.show-symbol-details",
+                Some(glib::VariantTy::STRING),
+                move |win, _, id| {
+                    if let Some(symbol) = id
+                        .and_then(|id| id.str())
+                        .and_then(classify::Symbol::from_id)
+                    {
+                        SymbolDetails::new(&symbol).present(Some(win));
+                    }
+                },
+            );
+
+            klass.install_action("win.show-catalogue", None, move |win, _, _| {
+                SymbolCatalogue::new().present(Some(win));
+            });
+
+            klass.install_action("win.new-custom-symbol", None, move |win, _, _| {
+                CustomSymbolDialog::new(win.imp().drawing_area.strokes()).present(Some(win));
+            });
+
+            klass.install_action("win.save-custom-symbols", None, move |win, _, _| {
+                win.save_custom_symbols();
+                // show the new symbol if it matches the current drawing
+                let strokes = win.imp().drawing_area.strokes();
+                if !strokes.is_empty() {
+                    win.classify(BoxedStrokes(strokes));
+                }
+            });
+
+            klass.install_action(
+                "win.remove-custom-symbol",
+                Some(glib::VariantTy::STRING),
+                move |win, _, id| {
+                    if let Some(id) = id.and_then(|id| id.str()) {
+                        win.remove_custom_symbol(id);
+                    }
+                },
+            );
+
+            klass.install_action_async("win.open-drawing", None, |win, _, _| async move {
+                win.open_drawing().await;
+            });
+
+            klass.install_action_async("win.save-drawing", None, |win, _, _| async move {
+                win.save_drawing().await;
+            });
+
+            klass.install_action_async("win.open-image", None, |win, _, _| async move {
+                win.open_image().await;
+            });
+
+            klass.install_action_async("win.paste-image", None, |win, _, _| async move {
+                win.paste_image().await;
+            });
+
+            klass.install_action_async("win.export-svg", None, |win, _, _| async move {
+                win.export_svg().await;
+            });
+
+            klass.install_action_async(
+                "win.export-png",
+                Some(glib::VariantTy::UINT32),
+                |win, _, scale| async move {
+                    let scale = scale.and_then(|scale| scale.get::<u32>()).unwrap_or(1);
+                    win.export_png(scale as f64).await;
+                },
+            );
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -110,8 +236,16 @@ mod imp {
                 )
                 .build();
 
+            obj.load_custom_symbols();
             obj.setup_symbol_list();
+            self.search_bar.set_key_capture_widget(Some(&*obj));
             obj.setup_classifier();
+            obj.setup_drop_target();
+            obj.setup_segmentation();
+            obj.setup_guides();
+            obj.setup_window_size();
+            obj.setup_drawing_mode();
+            obj.setup_shortcuts();
         }
 
         fn dispose(&self) {
@@ -172,91 +306,602 @@ impl HieroglyphicWindow {
             });
     }
 
+    /// Starts the classifier thread and listens for its results.
+    ///
+    /// If the thread panics, it is only started again by [`Self::send_to_classifier`]
+    /// with the next input, so there is never more than one thread.
     fn setup_classifier(&self) {
         let (req_tx, req_rx) = std::sync::mpsc::channel();
         let (res_tx, res_rx) = async_channel::bounded(1);
-        self.imp().classifier.set(req_tx).expect("Failed to set tx");
-        gio::spawn_blocking(move || {
-            tracing::info!("Classifier thread started");
-            let classifier = classify::Classifier::new().expect("Failed to setup classifier");
-
-            loop {
-                let Some(strokes) = req_rx.iter().next() else {
-                    //channel has hung up, cleanly exit
-                    tracing::info!("Exiting classifier thread");
-                    return;
-                };
+        self.imp().classifier.replace(Some(req_tx));
+        let worker = gio::spawn_blocking(move || run_classifier(req_rx, res_tx));
 
-                if strokes.is_empty() {
-                    tracing::warn!("Skipping classification on empty strokes");
-                    continue;
+        glib::spawn_future_local(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            async move {
+                if worker.await.is_err() {
+                    tracing::error!("Classifier thread panicked");
+                    window.show_toast(gettext("Recognition failed, please try again"));
                 }
-
-                let classifications: Option<Vec<&'static str>> = 'classify: {
-                    let start = Instant::now();
-                    let Some(results) = classifier.classify(strokes) else {
-                        tracing::warn!("Classifier returned None");
-                        break 'classify None;
-                    };
-                    tracing::info!(
-                        "Classification complete in {}ms",
-                        start.elapsed().as_millis()
-                    );
-                    Some(results)
-                };
-
-                res_tx
-                    .send_blocking(classifications)
-                    .expect("Failed to send classifications");
             }
-        });
+        ));
 
         glib::spawn_future_local(glib::clone!(
             #[weak(rename_to = window)]
             self,
             async move {
                 tracing::debug!("Listening for classifications");
-                while let Ok(Some(classifications)) = res_rx.recv().await {
-                    window.imp().stack.set_visible_child_name("symbols");
-                    let mut symbols = window
-                        .imp()
-                        .symbols
-                        .get()
-                        .cloned()
-                        .expect("`symbols` should be initialized in `setup_symbol_list`");
-
-                    symbols.remove_all();
-                    // switching out all 1k symbols takes too long, so only display the first 25
-                    symbols.extend(
-                        classifications
-                            .into_iter()
-                            .take(25)
-                            .map(&gtk::StringObject::new),
-                    );
-                    // scroll to top after updating symbols, so that the most likely symbols are
-                    // visible first
-                    window
-                        .imp()
-                        .symbol_list
-                        .adjustment()
-                        .expect("Failed to get symbol list adjustment")
-                        .set_value(0.0);
+                while let Ok(result) = res_rx.recv().await {
+                    match result {
+                        Ok(output) => window.show_output(output),
+                        Err(err) => window.show_classify_error(&err),
+                    }
                 }
             }
         ));
     }
 
+    /// Sends the input to the classifier thread, restarting it if it is no longer running.
+    fn send_to_classifier(&self, input: ClassifierInput) {
+        let sender = self.imp().classifier.borrow().clone();
+        let Some(Err(SendError(input))) = sender.map(|sender| sender.send(input)) else {
+            return;
+        };
+
+        tracing::warn!("Classifier thread is not running, restarting it");
+        self.setup_classifier();
+        if let Some(sender) = self.imp().classifier.borrow().as_ref() {
+            if sender.send(input).is_err() {
+                tracing::error!("Failed to send input to the classifier thread");
+            }
+        }
+    }
+
+    /// Shows the result of the classifier.
+    fn show_output(&self, output: ClassifierOutput) {
+        let classifications = match output {
+            ClassifierOutput::Symbols(classifications) => {
+                self.imp().formula_banner.set_revealed(false);
+                self.imp()
+                    .confusion_view
+                    .set_confusables(&classify::confusables(&classifications));
+                classifications
+            }
+            ClassifierOutput::Formula(classifications) => {
+                self.imp().confusion_view.set_confusables(&[]);
+                let banner = &self.imp().formula_banner;
+                banner.set_title(&classify::transcribe(&classifications));
+                banner.set_revealed(true);
+                classifications
+            }
+        };
+
+        // a new drawing replaces the search results
+        self.imp().search_bar.set_search_mode(false);
+        self.imp().search_entry.set_text("");
+        self.imp().stack.set_visible_child_name("symbols");
+        self.show_symbols(&classifications);
+        self.imp().classifications.replace(classifications);
+    }
+
+    /// Informs the user about an error of the classifier.
+    fn show_classify_error(&self, err: &classify::ClassifyError) {
+        match err {
+            // e.g. only empty strokes, which is not worth a notification
+            classify::ClassifyError::EmptyInput => tracing::debug!("{}", err),
+            classify::ClassifyError::Inference(_) => {
+                tracing::warn!("{}", err);
+                self.show_toast(gettext("Failed to recognize the symbol"));
+            }
+            classify::ClassifyError::ModelLoad(_) => {
+                tracing::error!("{}", err);
+                self.imp().formula_banner.set_revealed(false);
+                self.imp().confusion_view.set_confusables(&[]);
+                self.imp().stack.set_visible_child_name("error");
+            }
+        }
+    }
+
+    /// Shows the symbols with the given `ids` in the symbol list.
+    fn show_symbols(&self, ids: &[&'static str]) {
+        let mut symbols = self
+            .imp()
+            .symbols
+            .get()
+            .cloned()
+            .expect("`symbols` should be initialized in `setup_symbol_list`");
+
+        symbols.remove_all();
+        // switching out all 1k symbols takes too long, so only display the first 25
+        symbols.extend(ids.iter().take(25).copied().map(gtk::StringObject::new));
+        // scroll to top after updating symbols, so that the most likely symbols are
+        // visible first
+        self.imp()
+            .symbol_list
+            .adjustment()
+            .expect("Failed to get symbol list adjustment")
+            .set_value(0.0);
+    }
+
+    /// Shows the symbols matching the search query, or the last classification results if the
+    /// query is empty.
+    #[template_callback]
+    fn on_search_changed(&self) {
+        let query = self.imp().search_entry.text();
+        let (ids, empty_page) = if query.trim().is_empty() {
+            (self.imp().classifications.borrow().clone(), "placeholder")
+        } else {
+            (classify::search(&query, gettext), "no-results")
+        };
+
+        self.imp().stack.set_visible_child_name(if ids.is_empty() {
+            empty_page
+        } else {
+            "symbols"
+        });
+        self.show_symbols(&ids);
+    }
+
+    /// Sets up the action for switching between recognizing a single or multiple symbols.
+    fn setup_segmentation(&self) {
+        let settings = SETTINGS.with(|s| s.clone());
+        self.add_action(&settings.create_action("segment-symbols"));
+        settings.connect_changed(
+            Some("segment-symbols"),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |_, _| {
+                    // classify the current drawing again using the new mode
+                    let strokes = window.imp().drawing_area.strokes();
+                    if !strokes.is_empty() {
+                        window.classify(BoxedStrokes(strokes));
+                    }
+                }
+            ),
+        );
+    }
+
+    /// Sets up the action for showing guides on the canvas.
+    ///
+    /// The position relative to the guides is used to tell apart symbols like `\cdot` and `.`,
+    /// so the drawing is classified again when they are toggled.
+    fn setup_guides(&self) {
+        let settings = SETTINGS.with(|s| s.clone());
+        self.add_action(&settings.create_action("show-guides"));
+        settings
+            .bind("show-guides", &*self.imp().drawing_area, "show-guides")
+            .build();
+        settings.connect_changed(
+            Some("show-guides"),
+            glib::clone!(
+                #[weak(rename_to = window)]
+                self,
+                move |_, _| {
+                    let strokes = window.imp().drawing_area.strokes();
+                    if !strokes.is_empty() {
+                        window.classify(BoxedStrokes(strokes));
+                    }
+                }
+            ),
+        );
+    }
+
+    /// Restores the window size and stores it when it changes.
+    fn setup_window_size(&self) {
+        let settings = SETTINGS.with(|s| s.clone());
+        settings.bind("window-width", self, "default-width").build();
+        settings
+            .bind("window-height", self, "default-height")
+            .build();
+        settings.bind("is-maximized", self, "maximized").build();
+    }
+
+    /// Sets up the fullscreen drawing mode, which hides the symbol list
+    /// to make as much space as possible for the canvas.
+    fn setup_drawing_mode(&self) {
+        self.add_action(&gio::PropertyAction::new(
+            "fullscreen",
+            self,
+            "fullscreened",
+        ));
+        self.connect_fullscreened_notify(|window| {
+            window
+                .imp()
+                .split_view
+                .set_show_sidebar(!window.is_fullscreen());
+        });
+    }
+
+    /// Sets up the shortcuts for clearing the drawing and pasting an image.
+    ///
+    /// Unlike application accelerators, which run before the focused widget, the controller
+    /// only receives the keys the focused widget did not handle, so deleting and pasting
+    /// in text fields keeps working.
+    fn setup_shortcuts(&self) {
+        let controller = gtk::ShortcutController::new();
+        controller.set_propagation_phase(gtk::PropagationPhase::Bubble);
+        for (trigger, action) in [("Delete", "win.clear"), ("<Control>v", "win.paste-image")] {
+            controller.add_shortcut(gtk::Shortcut::new(
+                gtk::ShortcutTrigger::parse_string(trigger),
+                Some(gtk::NamedAction::new(action)),
+            ));
+        }
+        self.add_controller(controller);
+    }
+
+    /// Allows loading recorded drawings by dropping them onto the window.
+    fn setup_drop_target(&self) {
+        let drop_target = gtk::DropTarget::new(gio::File::static_type(), gdk::DragAction::COPY);
+        drop_target.connect_drop(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[upgrade_or]
+            false,
+            move |_, value, _, _| {
+                let Ok(file) = value.get::<gio::File>() else {
+                    return false;
+                };
+                glib::spawn_future_local(async move {
+                    window.load_drawing(&file).await;
+                });
+                true
+            }
+        ));
+        self.add_controller(drop_target);
+    }
+
+    /// Returns the file the custom symbols are stored in.
+    fn custom_symbols_file() -> PathBuf {
+        glib::user_data_dir()
+            .join(config::APP_ID)
+            .join("custom-symbols.json")
+    }
+
+    /// Loads the stored custom symbols, so they can be found and recognized.
+    fn load_custom_symbols(&self) {
+        let path = Self::custom_symbols_file();
+        let data = match std::fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return,
+            Err(err) => {
+                tracing::warn!("Failed to read custom symbols: {}", err);
+                self.imp().custom_symbols_unreadable.set(true);
+                self.show_toast(gettext("Failed to load custom symbols"));
+                return;
+            }
+        };
+
+        match classify::CustomSymbol::list_from_json(&data) {
+            Ok(symbols) => {
+                tracing::debug!("Loaded {} custom symbols", symbols.len());
+                classify::set_custom_symbols(symbols);
+            }
+            Err(err) => {
+                tracing::warn!("Failed to load custom symbols: {}", err);
+                self.imp().custom_symbols_unreadable.set(true);
+                self.show_toast(gettext("Failed to load custom symbols"));
+            }
+        }
+    }
+
+    /// Moves the custom symbols file that could not be loaded aside, e.g. one that is corrupt
+    /// or written by a newer version, so saving does not overwrite the symbols stored in it.
+    fn move_unreadable_custom_symbols(path: &Path) -> std::io::Result<()> {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        let backup = path.with_extension(format!("json.{}.bak", seconds));
+        match std::fs::rename(path, &backup) {
+            Ok(()) => tracing::info!("Moved unreadable custom symbols to {}", backup.display()),
+            // nothing is overwritten if the file was removed in the meantime
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
+
+    /// Stores the registered custom symbols.
+    ///
+    /// If the stored custom symbols could not be loaded, they are moved aside first and nothing
+    /// is stored if that fails.
+    fn save_custom_symbols(&self) {
+        let path = Self::custom_symbols_file();
+        if self.imp().custom_symbols_unreadable.get() {
+            if let Err(err) = Self::move_unreadable_custom_symbols(&path) {
+                tracing::warn!("Failed to move unreadable custom symbols: {}", err);
+                self.show_toast(gettext("Failed to save custom symbols"));
+                return;
+            }
+            self.imp().custom_symbols_unreadable.set(false);
+        }
+        let json = classify::CustomSymbol::list_to_json(&classify::custom_symbols());
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, json));
+
+        if let Err(err) = result {
+            tracing::warn!("Failed to save custom symbols: {}", err);
+            self.show_toast(gettext("Failed to save custom symbols"));
+        }
+    }
+
+    /// Removes the custom symbol with the given `id` and stores the remaining ones.
+    fn remove_custom_symbol(&self, id: &str) {
+        let Some(removed) = classify::Symbol::from_id(id).filter(|symbol| symbol.is_custom())
+        else {
+            return;
+        };
+        let mut symbols = classify::custom_symbols();
+        symbols.retain(|symbol| symbol.id() != removed.id());
+        classify::set_custom_symbols(symbols);
+        self.save_custom_symbols();
+
+        // the removed symbol must not be shown anymore
+        self.imp()
+            .classifications
+            .borrow_mut()
+            .retain(|&other| other != id);
+        self.on_search_changed();
+        self.show_toast(gettext("Removed “{}”").replace("{}", removed.command));
+    }
+
+    /// Returns a file filter for recorded drawings.
+    fn drawing_filters() -> gio::ListStore {
+        let filter = gtk::FileFilter::new();
+        filter.set_name(Some(&gettext("Drawings")));
+        filter.add_mime_type("application/json");
+        filter.add_suffix("json");
+
+        let filters = gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&filter);
+        filters
+    }
+
+    /// Lets the user choose a recorded drawing and replays it.
+    async fn open_drawing(&self) {
+        let dialog = gtk::FileDialog::builder()
+            .title(gettext("Open Drawing"))
+            .modal(true)
+            .filters(&Self::drawing_filters())
+            .build();
+
+        match dialog.open_future(Some(self)).await {
+            Ok(file) => self.load_drawing(&file).await,
+            Err(err) => tracing::debug!("No drawing selected: {}", err),
+        }
+    }
+
+    /// Loads the recorded drawing from `file` and replays it on the drawing area.
+    async fn load_drawing(&self, file: &gio::File) {
+        let recording = match file.load_contents_future().await {
+            Ok((contents, _)) => {
+                classify::Recording::from_json(&contents).map_err(|err| err.to_string())
+            }
+            Err(err) => Err(err.to_string()),
+        };
+
+        match recording {
+            Ok(recording) => {
+                tracing::debug!("Replaying drawing from {}", file.uri());
+                self.imp().drawing_area.replay(recording);
+            }
+            Err(err) => {
+                tracing::warn!("Failed to load drawing: {}", err);
+                self.show_toast(gettext("Failed to open drawing"));
+            }
+        }
+    }
+
+    /// Lets the user choose a file and saves `contents` to it.
+    ///
+    /// Returns whether the file has been saved.
+    async fn save_file(
+        &self,
+        title: String,
+        initial_name: &str,
+        filters: Option<&gio::ListStore>,
+        contents: impl AsRef<[u8]> + Send + 'static,
+    ) -> bool {
+        let dialog = gtk::FileDialog::builder()
+            .title(title)
+            .modal(true)
+            .initial_name(initial_name)
+            .build();
+        dialog.set_filters(filters);
+
+        let file = match dialog.save_future(Some(self)).await {
+            Ok(file) => file,
+            Err(err) => {
+                tracing::debug!("No file selected: {}", err);
+                return false;
+            }
+        };
+
+        match file
+            .replace_contents_future(
+                contents,
+                None,
+                false,
+                gio::FileCreateFlags::REPLACE_DESTINATION,
+            )
+            .await
+        {
+            Ok(_) => true,
+            Err((_, err)) => {
+                tracing::warn!("Failed to save file: {}", err);
+                self.show_toast(gettext("Failed to save file"));
+                false
+            }
+        }
+    }
+
+    /// Lets the user choose a file and saves the current drawing to it.
+    async fn save_drawing(&self) {
+        let recording = self.imp().drawing_area.recording();
+        if self
+            .save_file(
+                gettext("Save Drawing"),
+                "drawing.json",
+                Some(&Self::drawing_filters()),
+                recording.to_json(),
+            )
+            .await
+        {
+            self.show_toast(gettext("Drawing saved"));
+        }
+    }
+
+    /// Lets the user choose a file and exports the current drawing as SVG to it.
+    async fn export_svg(&self) {
+        let recording = self.imp().drawing_area.recording();
+        let svg = match export::svg(&recording.strokes(), recording.width, recording.height, 1.0) {
+            Ok(svg) => svg,
+            Err(err) => {
+                tracing::warn!("Failed to export drawing: {}", err);
+                self.show_toast(gettext("Failed to export drawing"));
+                return;
+            }
+        };
+        if self
+            .save_file(gettext("Export Drawing"), "drawing.svg", None, svg)
+            .await
+        {
+            self.show_toast(gettext("Drawing exported"));
+        }
+    }
+
+    /// Lets the user choose a file and exports the current drawing as PNG to it,
+    /// scaled by `scale`.
+    async fn export_png(&self, scale: f64) {
+        let recording = self.imp().drawing_area.recording();
+        let png = match export::png(
+            &recording.strokes(),
+            recording.width,
+            recording.height,
+            scale,
+        ) {
+            Ok(png) => png,
+            Err(err) => {
+                tracing::warn!("Failed to export drawing: {}", err);
+                self.show_toast(gettext("Failed to export drawing"));
+                return;
+            }
+        };
+        if self
+            .save_file(gettext("Export Drawing"), "drawing.png", None, png)
+            .await
+        {
+            self.show_toast(gettext("Drawing exported"));
+        }
+    }
+
     /// Classify the given strokes.
     #[template_callback]
     fn classify(&self, BoxedStrokes(strokes): BoxedStrokes) {
-        // we clone the strokes to the window, so we can upload them later on
-        self.imp().symbol_strokes.replace(Some(strokes.clone()));
-        self.imp()
-            .classifier
-            .get()
-            .unwrap()
-            .send(strokes)
-            .expect("Failed to send strokes");
+        let input = if SETTINGS.with(|s| s.boolean("segment-symbols")) {
+            // a formula is not labeled by a single symbol, so it is not uploaded
+            self.imp().symbol_strokes.replace(None);
+            ClassifierInput::Formula(strokes)
+        } else {
+            // only notify once, when the first stroke is ignored
+            let drawn = strokes
+                .iter()
+                .filter(|stroke| classify::is_drawn(stroke))
+                .count();
+            if drawn == classify::MAX_STROKES + 1 && strokes.last().is_some_and(classify::is_drawn)
+            {
+                self.show_toast(
+                    gettext("Only the first {} strokes are used for recognition")
+                        .replace("{}", &classify::MAX_STROKES.to_string()),
+                );
+            }
+            let drawing_area = &self.imp().drawing_area;
+            let position = drawing_area
+                .show_guides()
+                .then(|| classify::vertical_position(&strokes, drawing_area.height() as f64))
+                .flatten();
+            // we clone the strokes to the window, so we can upload them later on
+            self.imp().symbol_strokes.replace(Some(strokes.clone()));
+            ClassifierInput::Strokes(strokes, position)
+        };
+        self.send_to_classifier(input);
+    }
+
+    /// Copies the recognized formula to the clipboard.
+    #[template_callback]
+    fn copy_formula(&self) {
+        let formula = self.imp().formula_banner.title();
+        self.clipboard().set_text(&formula);
+        self.show_toast(gettext("Copied “{}”").replace("{}", &formula));
+    }
+
+    /// Lets the user choose an image and classifies the symbol in it.
+    async fn open_image(&self) {
+        let filter = gtk::FileFilter::new();
+        filter.set_name(Some(&gettext("Images")));
+        filter.add_mime_type("image/*");
+        let filters = gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&filter);
+
+        let dialog = gtk::FileDialog::builder()
+            .title(gettext("Open Image"))
+            .modal(true)
+            .filters(&filters)
+            .build();
+
+        let file = match dialog.open_future(Some(self)).await {
+            Ok(file) => file,
+            Err(err) => {
+                tracing::debug!("No image selected: {}", err);
+                return;
+            }
+        };
+
+        match gdk::Texture::from_file(&file) {
+            Ok(texture) => self.classify_texture(&texture),
+            Err(err) => {
+                tracing::warn!("Failed to load image: {}", err);
+                self.show_toast(gettext("Failed to open image"));
+            }
+        }
+    }
+
+    /// Classifies the symbol in the image from the clipboard.
+    async fn paste_image(&self) {
+        match self.clipboard().read_texture_future().await {
+            Ok(Some(texture)) => self.classify_texture(&texture),
+            Ok(None) => self.show_toast(gettext("No image in clipboard")),
+            Err(err) => {
+                tracing::debug!("Failed to read image from clipboard: {}", err);
+                self.show_toast(gettext("No image in clipboard"));
+            }
+        }
+    }
+
+    /// Classifies the symbol shown in the given texture.
+    fn classify_texture(&self, texture: &gdk::Texture) {
+        let mut downloader = gdk::TextureDownloader::new(texture);
+        downloader.set_format(gdk::MemoryFormat::R8g8b8a8);
+        let (data, stride) = downloader.download_bytes();
+        let image = classify::Image::from_rgba(
+            texture.width() as usize,
+            texture.height() as usize,
+            stride,
+            &data,
+        );
+
+        if image.is_empty() {
+            self.show_toast(gettext("No symbol found in image"));
+            return;
+        }
+
+        self.imp().drawing_area.clear();
+        // images are not uploaded, since the training data only consists of strokes
+        self.imp().symbol_strokes.replace(None);
+        self.send_to_classifier(ClassifierInput::Image(image));
     }
 
     #[template_callback]
@@ -265,14 +910,26 @@ impl HieroglyphicWindow {
         let Some(symbol) = binding.and_downcast_ref::<SymbolItem>() else {
             return;
         };
+        self.copy_symbol(&symbol.id());
+    }
 
-        let command = symbol.command();
-        self.clipboard().set_text(&command);
-        tracing::debug!("Selected: {} ({})", &command, symbol.id());
-        self.show_toast(gettext("Copied “{}”").replace("{}", &command));
+    /// Copies the command of the symbol with the given `id` and uploads the drawn strokes
+    /// labelled with it, if enabled.
+    fn copy_symbol(&self, id: &str) {
+        let Some(symbol) = classify::Symbol::from_id(id) else {
+            return;
+        };
+
+        let command = symbol.command;
+        self.clipboard().set_text(command);
+        tracing::debug!("Selected: {} ({})", command, id);
+        self.show_toast(gettext("Copied “{}”").replace("{}", command));
 
-        if let Some(strokes) = self.imp().symbol_strokes.take() {
-            self.try_upload_data(symbol.id(), strokes);
+        let strokes = self.imp().symbol_strokes.take();
+        // custom symbols are only known locally, so their drawings are never uploaded
+        if let Some(strokes) = strokes.filter(|_| !symbol.is_custom()) {
+            // the training data is labelled with the legacy ids, see `Symbol::legacy_id`
+            self.try_upload_data(symbol.legacy_id(), strokes);
         }
     }
 
@@ -318,3 +975,70 @@ impl HieroglyphicWindow {
         });
     }
 }
+
+/// Classifies all inputs from `requests` and sends the results to `results`,
+/// until either channel is closed or the model cannot be loaded.
+fn run_classifier(
+    requests: Receiver<ClassifierInput>,
+    results: async_channel::Sender<Result<ClassifierOutput, classify::ClassifyError>>,
+) {
+    tracing::info!("Classifier thread started");
+    let classifier = match classify::Classifier::new() {
+        Ok(classifier) => classifier,
+        Err(err) => {
+            // the window shows the error and starts a new thread on the next input
+            let _ = results.send_blocking(Err(err));
+            return;
+        }
+    };
+
+    for input in requests.iter() {
+        let start = Instant::now();
+        let result = match input {
+            ClassifierInput::Strokes(strokes, position) => {
+                // custom symbols are not known to the model, so they are matched separately
+                let custom_matches = classify::match_custom_symbols(&strokes);
+                classifier.classify(strokes).map(|mut results| {
+                    if let Some(position) = position {
+                        classify::rerank_by_position(&mut results, position);
+                    }
+                    // the model may predict several commands for the same glyph
+                    classify::dedup_aliases(&mut results);
+                    classify::insert_custom_matches(&mut results, &custom_matches);
+                    ClassifierOutput::Symbols(results)
+                })
+            }
+            ClassifierInput::Image(image) => {
+                classifier.classify_image(&image).map(|mut results| {
+                    classify::dedup_aliases(&mut results);
+                    ClassifierOutput::Symbols(results)
+                })
+            }
+            ClassifierInput::Formula(strokes) => {
+                let segments = classify::segment(strokes);
+                if segments.is_empty() {
+                    Err(classify::ClassifyError::EmptyInput)
+                } else {
+                    segments
+                        .into_iter()
+                        .map(|segment| classifier.classify(segment).map(|results| results[0]))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(ClassifierOutput::Formula)
+                }
+            }
+        };
+        if result.is_ok() {
+            tracing::info!(
+                "Classification complete in {}ms",
+                start.elapsed().as_millis()
+            );
+        }
+
+        if results.send_blocking(result).is_err() {
+            // the window has been closed
+            break;
+        }
+    }
+    // channel has hung up, cleanly exit
+    tracing::info!("Exiting classifier thread");
+}
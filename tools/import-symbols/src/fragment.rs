@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Modes in which a symbol is available, named like the keys of `symbols.yaml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Math,
+    Text,
+    Both,
+}
+
+impl Mode {
+    const ALL: [Mode; 3] = [Mode::Math, Mode::Text, Mode::Both];
+
+    /// Returns the key of the mode in `symbols.yaml`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Mode::Math => "mathmode",
+            Mode::Text => "textmode",
+            Mode::Both => "bothmodes",
+        }
+    }
+}
+
+/// A symbol to add to `symbols.yaml`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub command: String,
+    pub mode: Mode,
+    /// Font encoding of the symbol, `None` for the default OT1 encoding.
+    pub font_encoding: Option<String>,
+}
+
+/// Symbols listed under one table of `symbols.yaml`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    /// Title of the table, `None` if the symbols are added to an existing table.
+    pub title: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+/// Symbols of a package, which can be appended to `symbols.yaml`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fragment {
+    pub package: String,
+    pub tables: Vec<Table>,
+}
+
+impl Fragment {
+    /// Returns the commands of all symbols.
+    pub fn commands(&self) -> impl Iterator<Item = &str> {
+        self.tables
+            .iter()
+            .flat_map(|table| &table.entries)
+            .map(|entry| entry.command.as_str())
+    }
+
+    /// Removes all symbols that are listed more than once, keeping the first one,
+    /// and those for which `exists` returns true.
+    pub fn dedup(&mut self, exists: impl Fn(&str) -> bool) {
+        let mut seen = HashSet::new();
+        for table in &mut self.tables {
+            table
+                .entries
+                .retain(|entry| !exists(&entry.command) && seen.insert(entry.command.clone()));
+        }
+        self.tables
+            .retain(|table| table.title.is_some() || !table.entries.is_empty());
+    }
+
+    /// Formats the fragment in the format of `symbols.yaml`.
+    ///
+    /// Symbols are grouped by their font encoding and mode, keeping their order otherwise.
+    pub fn to_yaml(&self) -> String {
+        let mut yaml = String::new();
+        // writing to a string cannot fail, so the results are ignored
+        for table in &self.tables {
+            if let Some(title) = &table.title {
+                let _ = writeln!(yaml, "- table: {}", scalar(title));
+            }
+
+            let mut encodings: Vec<Option<&str>> = Vec::new();
+            for entry in &table.entries {
+                if !encodings.contains(&entry.font_encoding.as_deref()) {
+                    encodings.push(entry.font_encoding.as_deref());
+                }
+            }
+
+            for encoding in encodings {
+                let mut keys = Vec::new();
+                if self.package != "latex2e" {
+                    keys.push(format!("package: {}", scalar(&self.package)));
+                }
+                if let Some(encoding) = encoding {
+                    keys.push(format!("fontenc: {}", scalar(encoding)));
+                }
+                for mode in Mode::ALL {
+                    let commands = table
+                        .entries
+                        .iter()
+                        .filter(|entry| entry.mode == mode)
+                        .filter(|entry| entry.font_encoding.as_deref() == encoding)
+                        .map(|entry| format!("\n    - {}", scalar(&entry.command)))
+                        .collect::<String>();
+                    if !commands.is_empty() {
+                        keys.push(format!("{}:{}", mode.key(), commands));
+                    }
+                }
+
+                for (i, key) in keys.iter().enumerate() {
+                    let _ = writeln!(yaml, "{}{}", if i == 0 { "- " } else { "  " }, key);
+                }
+            }
+        }
+        yaml
+    }
+}
+
+/// Returns the commands listed for the `package` in `symbols.yaml`.
+///
+/// This only understands the layout of `symbols.yaml` itself, i.e. one item per line and
+/// the package as a key of top-level items, rather than arbitrary YAML.
+pub fn existing_commands(symbols: &str, package: &str) -> HashSet<String> {
+    let mut commands = HashSet::new();
+    let mut current = "latex2e";
+    for line in symbols.lines() {
+        let line = line.split(" #").next().unwrap_or_default().trim_end();
+        if line.starts_with("- ") {
+            // a new top-level item, which does not belong to a package unless it states one
+            current = "latex2e";
+        }
+        let content = line.trim_start().trim_start_matches("- ");
+        if let Some(value) = content.strip_prefix("package:") {
+            current = value.trim();
+            continue;
+        }
+        let command = content.strip_prefix("command:").unwrap_or(content).trim();
+        if current == package && command.starts_with('\\') {
+            commands.insert(command.to_owned());
+        }
+    }
+    commands
+}
+
+/// Formats `value` as a YAML scalar, quoting it if it would otherwise not be parsed
+/// as the same string.
+fn scalar(value: &str) -> String {
+    const INDICATORS: &[char] = &[
+        '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%', '@',
+        '`',
+    ];
+    const KEYWORDS: &[&str] = &[
+        "true", "false", "yes", "no", "on", "off", "null", "~", ".inf", "-.inf", ".nan", "inf",
+        "infinity", "nan",
+    ];
+
+    let plain = !value.is_empty()
+        && !value.starts_with(INDICATORS)
+        && !value.starts_with(char::is_whitespace)
+        && !value.ends_with(char::is_whitespace)
+        && !value.ends_with(':')
+        && !value.contains(": ")
+        && !value.contains(" #")
+        && !KEYWORDS.contains(&value.to_lowercase().as_str())
+        && value.parse::<f64>().is_err();
+    if plain {
+        value.to_owned()
+    } else {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(command: &str, mode: Mode, font_encoding: Option<&str>) -> Entry {
+        Entry {
+            command: command.to_owned(),
+            mode,
+            font_encoding: font_encoding.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn test_to_yaml() {
+        let fragment = Fragment {
+            package: "stmaryrd".to_owned(),
+            tables: vec![Table {
+                title: Some("stmaryrd Arrows".to_owned()),
+                entries: vec![
+                    entry("\\Mapsfrom", Mode::Math, None),
+                    entry("\\textfoo", Mode::Text, Some("T1")),
+                    entry("\\mapsfrom", Mode::Math, None),
+                    entry("\\foo", Mode::Both, None),
+                ],
+            }],
+        };
+        assert_eq!(
+            fragment.to_yaml(),
+            "- table: stmaryrd Arrows
+- package: stmaryrd
+  mathmode:
+    - \\Mapsfrom
+    - \\mapsfrom
+  bothmodes:
+    - \\foo
+- package: stmaryrd
+  fontenc: T1
+  textmode:
+    - \\textfoo
+"
+        );
+    }
+
+    #[test]
+    fn test_to_yaml_without_package() {
+        let fragment = Fragment {
+            package: "latex2e".to_owned(),
+            tables: vec![Table {
+                title: None,
+                entries: vec![entry("\\foo", Mode::Math, None)],
+            }],
+        };
+        assert_eq!(fragment.to_yaml(), "- mathmode:\n    - \\foo\n");
+    }
+
+    #[test]
+    fn test_scalar() {
+        assert_eq!(scalar("\\alpha"), "\\alpha");
+        assert_eq!(scalar("Greek Letters"), "Greek Letters");
+        assert_eq!(scalar("!`"), "'!`'");
+        assert_eq!(scalar("infinity"), "'infinity'");
+        assert_eq!(scalar("True"), "'True'");
+        assert_eq!(scalar("1.5"), "'1.5'");
+        assert_eq!(scalar("Arrows: Part 1"), "'Arrows: Part 1'");
+        assert_eq!(scalar("it's"), "it's");
+        assert_eq!(scalar("'quoted'"), "'''quoted'''");
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut fragment = Fragment {
+            package: "foo".to_owned(),
+            tables: vec![
+                Table {
+                    title: None,
+                    entries: vec![
+                        entry("\\a", Mode::Math, None),
+                        entry("\\b", Mode::Math, None),
+                    ],
+                },
+                Table {
+                    title: None,
+                    entries: vec![entry("\\a", Mode::Text, None)],
+                },
+            ],
+        };
+        fragment.dedup(|command| command == "\\b");
+        assert_eq!(fragment.commands().collect::<Vec<_>>(), vec!["\\a"]);
+        assert_eq!(fragment.tables.len(), 1);
+    }
+
+    #[test]
+    fn test_existing_commands() {
+        let symbols = "- table: Arrows
+- \\textbar
+- package: stmaryrd
+  mathmode:
+    - \\Mapsfrom # comment
+    - command: \\mapsfrom
+      description: maps from
+- fontenc: T1
+  package: stmaryrd
+  textmode:
+    - \\textfoo
+- mathmode:
+    - \\leftarrow
+";
+        let mut stmaryrd: Vec<_> = existing_commands(symbols, "stmaryrd").into_iter().collect();
+        stmaryrd.sort();
+        assert_eq!(stmaryrd, vec!["\\Mapsfrom", "\\mapsfrom", "\\textfoo"]);
+
+        let mut latex2e: Vec<_> = existing_commands(symbols, "latex2e").into_iter().collect();
+        latex2e.sort();
+        assert_eq!(latex2e, vec!["\\leftarrow", "\\textbar"]);
+    }
+}
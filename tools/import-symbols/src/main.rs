@@ -0,0 +1,159 @@
+//! Generates entries for `symbols.yaml` from a LaTeX package.
+//!
+//! The symbols are either scanned from the `.sty` file of the package or read from a manifest,
+//! see [`manifest::parse`] for its format. The resulting YAML fragment is written to stdout and
+//! should be reviewed before appending it to `symbols.yaml`, as not every command defined by a
+//! package is a symbol.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod fragment;
+mod manifest;
+mod sty;
+
+use fragment::{Fragment, Table};
+
+const USAGE: &str = "\
+Usage: import-symbols [OPTIONS] FILE
+
+Generates entries for symbols.yaml from a .sty file or a package manifest.
+
+Options:
+  --package NAME       name of the package, defaults to the name of the .sty file
+  --table TITLE        title of the table the symbols are listed under
+  --symbols FILE       symbols.yaml, to skip symbols which are already listed
+  -h, --help           print this help";
+
+#[derive(Debug, Default)]
+struct Options {
+    package: Option<String>,
+    table: Option<String>,
+    symbols: Option<PathBuf>,
+    file: PathBuf,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut file = None;
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        match arg.as_str() {
+            "--package" => options.package = Some(value(&arg)?),
+            "--table" => options.table = Some(value(&arg)?),
+            "--symbols" => options.symbols = Some(value(&arg)?.into()),
+            "-h" | "--help" => return Err(USAGE.to_owned()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ if file.is_some() => return Err(format!("unexpected argument {}", arg)),
+            _ => file = Some(PathBuf::from(arg)),
+        }
+    }
+    options.file = file.ok_or(USAGE)?;
+    Ok(options)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {}", path.display(), err))
+}
+
+fn run(options: Options) -> Result<Fragment, String> {
+    let source = read(&options.file)?;
+    let mut fragment = if options.file.extension().is_some_and(|ext| ext == "sty") {
+        let package = options.file.file_stem().unwrap_or_default();
+        Fragment {
+            package: package.to_string_lossy().into_owned(),
+            tables: vec![Table {
+                title: None,
+                entries: sty::scan(&source),
+            }],
+        }
+    } else {
+        manifest::parse(&source).map_err(|err| format!("{}: {}", options.file.display(), err))?
+    };
+
+    if let Some(package) = options.package {
+        fragment.package = package;
+    }
+    if let Some(title) = options.table {
+        // symbols listed before the first table of a manifest belong to the given one
+        match fragment.tables.first_mut() {
+            Some(table) if table.title.is_none() => table.title = Some(title),
+            _ => fragment.tables.insert(
+                0,
+                Table {
+                    title: Some(title),
+                    entries: Vec::new(),
+                },
+            ),
+        }
+    }
+
+    let existing = match &options.symbols {
+        Some(path) => fragment::existing_commands(&read(path)?, &fragment.package),
+        None => Default::default(),
+    };
+    fragment.dedup(|command| existing.contains(command));
+    Ok(fragment)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let fragment = match run(options) {
+        Ok(fragment) => fragment,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let count = fragment.commands().count();
+    if count == 0 {
+        eprintln!("no new symbols found");
+        return ExitCode::SUCCESS;
+    }
+    println!(
+        "# generated by import-symbols for {}, please review before committing",
+        fragment.package
+    );
+    print!("{}", fragment.to_yaml());
+
+    eprintln!("found {} new symbols", count);
+    if fragment.tables.iter().any(|table| table.title.is_some()) {
+        eprintln!("add the titles of new tables to data/symbol-strings.h to translate them");
+    }
+    eprintln!("render the icons of the new symbols with tools/render-icons");
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(args(&["--package", "foo", "--table", "Foo", "foo.txt"])).unwrap();
+        assert_eq!(options.package.as_deref(), Some("foo"));
+        assert_eq!(options.table.as_deref(), Some("Foo"));
+        assert_eq!(options.symbols, None);
+        assert_eq!(options.file, PathBuf::from("foo.txt"));
+
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["--package"])).is_err());
+        assert!(parse_args(args(&["--unknown", "foo.sty"])).is_err());
+        assert!(parse_args(args(&["foo.sty", "bar.sty"])).is_err());
+    }
+}
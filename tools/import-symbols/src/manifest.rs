@@ -0,0 +1,119 @@
+use crate::fragment::{Entry, Fragment, Mode, Table};
+
+/// Parses a package manifest, which lists the symbols of a package, e.g. as they are listed
+/// in [The Comprehensive LaTeX Symbol List](https://ctan.org/pkg/comprehensive).
+///
+/// Each line consists of a key and a value, separated by a colon, and `#` starts a comment:
+///
+/// ```text
+/// package: stmaryrd
+/// table: stmaryrd Arrows
+/// mathmode: \Mapsto \mapsfrom
+/// fontenc: T1
+/// textmode: \textfoo
+/// ```
+///
+/// `mathmode`, `textmode` and `bothmodes` list commands separated by whitespace, which use the
+/// font encoding of the last `fontenc` line. `table` starts a new table. Commands before the
+/// first table are added to an existing one.
+pub fn parse(manifest: &str) -> Result<Fragment, String> {
+    let mut fragment = Fragment {
+        package: "latex2e".to_owned(),
+        tables: vec![Table::default()],
+    };
+    let mut font_encoding = None;
+
+    for (number, line) in manifest.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(format!("line {}: expected `key: value`", number + 1));
+        };
+
+        let value = value.trim();
+        let mode = match key.trim() {
+            "package" => {
+                fragment.package = value.to_owned();
+                continue;
+            }
+            "fontenc" => {
+                font_encoding = (value != "OT1").then(|| value.to_owned());
+                continue;
+            }
+            "table" => {
+                fragment.tables.push(Table {
+                    title: Some(value.to_owned()),
+                    entries: Vec::new(),
+                });
+                continue;
+            }
+            "mathmode" => Mode::Math,
+            "textmode" => Mode::Text,
+            "bothmodes" => Mode::Both,
+            key => return Err(format!("line {}: unknown key `{}`", number + 1, key)),
+        };
+
+        let table = fragment
+            .tables
+            .last_mut()
+            .expect("There always is the table for commands before the first table");
+        table
+            .entries
+            .extend(value.split_whitespace().map(|command| Entry {
+                command: command.to_owned(),
+                mode,
+                font_encoding: font_encoding.clone(),
+            }));
+    }
+
+    fragment
+        .tables
+        .retain(|table| table.title.is_some() || !table.entries.is_empty());
+    Ok(fragment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let manifest = r"
+# symbols of stmaryrd
+package: stmaryrd
+table: stmaryrd Arrows
+mathmode: \Mapsto \mapsfrom
+mathmode: \shortleftarrow # more arrows
+fontenc: T1
+textmode: \textfoo
+";
+        let fragment = parse(manifest).unwrap();
+        assert_eq!(fragment.package, "stmaryrd");
+        assert_eq!(fragment.tables.len(), 1);
+        assert_eq!(fragment.tables[0].title.as_deref(), Some("stmaryrd Arrows"));
+        assert_eq!(
+            fragment.commands().collect::<Vec<_>>(),
+            vec!["\\Mapsto", "\\mapsfrom", "\\shortleftarrow", "\\textfoo"]
+        );
+        let textfoo = &fragment.tables[0].entries[3];
+        assert_eq!(textfoo.mode, Mode::Text);
+        assert_eq!(textfoo.font_encoding.as_deref(), Some("T1"));
+    }
+
+    #[test]
+    fn test_existing_table() {
+        let fragment = parse("mathmode: \\foo\ntable: New\nbothmodes: \\bar").unwrap();
+        assert_eq!(fragment.package, "latex2e");
+        assert_eq!(fragment.tables[0].title, None);
+        assert_eq!(fragment.tables[1].title.as_deref(), Some("New"));
+        assert_eq!(fragment.tables[1].entries[0].mode, Mode::Both);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("package stmaryrd").is_err());
+        assert!(parse("symbols: \\foo").is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::fragment::{Entry, Mode};
+
+/// Scans the source of a `.sty` file for symbol definitions.
+///
+/// Recognized are `\DeclareMathSymbol` (math mode), `\DeclareTextSymbol` and
+/// `\DeclareTextCommand` (text mode, with their font encoding), as well as `\newcommand` and
+/// its variants without arguments. The mode of the latter is guessed: commands using
+/// `\ensuremath` work in both modes, commands starting with `\text` in text mode and all
+/// others in math mode.
+///
+/// Internal commands, i.e. those containing `@`, are skipped.
+pub fn scan(source: &str) -> Vec<Entry> {
+    let source = strip_comments(source);
+    let mut scanner = Scanner::new(&source);
+    let mut entries = Vec::new();
+
+    while let Some(name) = scanner.next_control_word() {
+        let entry = match name.as_str() {
+            "DeclareMathSymbol" => scanner.argument().map(|command| Entry {
+                command,
+                mode: Mode::Math,
+                font_encoding: None,
+            }),
+            "DeclareTextSymbol" | "DeclareTextCommand" => {
+                let command = scanner.argument();
+                let encoding = scanner.argument();
+                command.zip(encoding).map(|(command, encoding)| Entry {
+                    command,
+                    mode: Mode::Text,
+                    font_encoding: (encoding != "OT1").then_some(encoding),
+                })
+            }
+            "newcommand" | "renewcommand" | "providecommand" | "DeclareRobustCommand" => {
+                scanner.star();
+                let command = scanner.argument();
+                let arguments = scanner.optional();
+                // the default value of an optional argument
+                scanner.optional();
+                let body = scanner.argument().unwrap_or_default();
+                command
+                    .filter(|_| arguments.is_none_or(|n| n.trim() == "0"))
+                    .map(|command| Entry {
+                        mode: if body.contains("\\ensuremath") {
+                            Mode::Both
+                        } else if command.starts_with("\\text") {
+                            Mode::Text
+                        } else {
+                            Mode::Math
+                        },
+                        command,
+                        font_encoding: None,
+                    })
+            }
+            _ => None,
+        };
+
+        if let Some(entry) = entry.filter(|entry| is_public_command(&entry.command)) {
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// Returns whether `command` is a control word that can be used in a document,
+/// e.g. `\foo`, but neither `\f@o` nor `#1`.
+fn is_public_command(command: &str) -> bool {
+    command
+        .strip_prefix('\\')
+        .is_some_and(|name| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// Removes all comments, i.e. everything from an unescaped `%` to the end of the line.
+fn strip_comments(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            let mut escaped = false;
+            let end = line
+                .char_indices()
+                .find(|&(_, c)| {
+                    let comment = c == '%' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    comment
+                })
+                .map_or(line.len(), |(i, _)| i);
+            &line[..end]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Minimal reader for TeX source, which only understands control sequences and groups.
+struct Scanner<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Advances to the next control word and returns its name without the backslash.
+    ///
+    /// `@` is treated as a letter, like in `.sty` files.
+    fn next_control_word(&mut self) -> Option<String> {
+        loop {
+            let start = self.rest().find('\\')? + self.position + 1;
+            let name: String = self.source[start..]
+                .chars()
+                .take_while(|&c| c.is_ascii_alphabetic() || c == '@')
+                .collect();
+            // skip escaped characters like `\\` or `\{` as a whole
+            let length = if name.is_empty() {
+                self.source[start..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8)
+            } else {
+                name.len()
+            };
+            self.position = start + length;
+            if !name.is_empty() {
+                return Some(name);
+            }
+        }
+    }
+
+    /// Reads the next argument, i.e. the content of a group or a single token.
+    fn argument(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let mut chars = rest.chars();
+        let argument = match chars.next()? {
+            '{' => {
+                let end = group_end(rest, '{', '}')?;
+                self.position += end + 1;
+                return Some(rest[1..end].trim().to_owned());
+            }
+            '\\' => {
+                let name: String = chars
+                    .clone()
+                    .take_while(|&c| c.is_ascii_alphabetic() || c == '@')
+                    .collect();
+                if name.is_empty() {
+                    format!("\\{}", chars.next()?)
+                } else {
+                    format!("\\{}", name)
+                }
+            }
+            c => c.to_string(),
+        };
+        self.position += argument.len();
+        Some(argument)
+    }
+
+    /// Reads an optional argument in brackets, if there is one.
+    fn optional(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        if !rest.starts_with('[') {
+            return None;
+        }
+        let end = group_end(rest, '[', ']')?;
+        self.position += end + 1;
+        Some(rest[1..end].to_owned())
+    }
+
+    /// Skips a `*`, e.g. of `\newcommand*`.
+    fn star(&mut self) {
+        self.skip_whitespace();
+        if self.rest().starts_with('*') {
+            self.position += 1;
+        }
+    }
+}
+
+/// Returns the index of the delimiter closing the group `text` starts with,
+/// ignoring escaped and nested delimiters.
+fn group_end(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(source: &str) -> Vec<(String, Mode, Option<String>)> {
+        scan(source)
+            .into_iter()
+            .map(|entry| (entry.command, entry.mode, entry.font_encoding))
+            .collect()
+    }
+
+    #[test]
+    fn test_math_symbols() {
+        let source = r#"
+\DeclareMathSymbol{\Mapsto}{\mathrel}{stmry@a}{"7A}
+\DeclareMathSymbol\mapsfrom{\mathrel}{stmry@a}{"7B}
+% \DeclareMathSymbol{\commented}{\mathrel}{stmry@a}{"7C}
+\DeclareMathSymbol{\stmry@internal}{\mathrel}{stmry@a}{"7D}
+"#;
+        assert_eq!(
+            commands(source),
+            vec![
+                ("\\Mapsto".to_owned(), Mode::Math, None),
+                ("\\mapsfrom".to_owned(), Mode::Math, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_symbols() {
+        let source = r"
+\DeclareTextSymbol{\textohorn}{T5}{161}
+\DeclareTextCommand{\textfoo}{T1}{\char 42}
+\DeclareTextSymbol{\textbar}{OT1}{124}
+";
+        assert_eq!(
+            commands(source),
+            vec![
+                ("\\textohorn".to_owned(), Mode::Text, Some("T5".to_owned())),
+                ("\\textfoo".to_owned(), Mode::Text, Some("T1".to_owned())),
+                ("\\textbar".to_owned(), Mode::Text, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_newcommand() {
+        let source = r"
+\newcommand{\R}{\mathbb{R}}
+\newcommand*\norm[1]{\lVert#1\rVert}
+\renewcommand{\ip}[0]{\langle\cdot,\cdot\rangle}
+\providecommand{\textsmiley}{\char 1}
+\DeclareRobustCommand{\both}{\ensuremath{\star}}
+\newcommand{\opt}[1][x]{#1}
+\newcommand{\escaped}{\{ \} 100\% }
+\newcommand{\after}{\after@}
+";
+        assert_eq!(
+            commands(source),
+            vec![
+                ("\\R".to_owned(), Mode::Math, None),
+                ("\\ip".to_owned(), Mode::Math, None),
+                ("\\textsmiley".to_owned(), Mode::Text, None),
+                ("\\both".to_owned(), Mode::Both, None),
+                ("\\escaped".to_owned(), Mode::Math, None),
+                ("\\after".to_owned(), Mode::Math, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(strip_comments("a % b\n100\\% c%d\n%"), "a \n100\\% c\n");
+        assert_eq!(strip_comments("\\\\% comment"), "\\\\");
+    }
+}
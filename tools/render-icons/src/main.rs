@@ -0,0 +1,189 @@
+//! Keeps the icons in `data/resources/icons/symbols` in sync with `symbols.yaml`.
+//!
+//! Missing icons are rendered with `latex` and `dvisvgm` from a local TeX installation, and the
+//! icons listed in `data/resources/resources.gresource.xml` are updated. The build script
+//! checks the same, so adding a symbol only requires adding it to `symbols.yaml` and running
+//! this tool.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// only the ids are needed, not the legacy ones
+#[allow(dead_code)]
+#[path = "../../../src/classify/id.rs"]
+mod id;
+mod render;
+mod resources;
+// the fields needed for the symbol table are not used here
+#[allow(dead_code)]
+#[path = "../../../build-aux/symbols.rs"]
+mod symbols;
+
+use symbols::Symbol;
+
+const USAGE: &str = "\
+Usage: render-icons [OPTIONS]
+
+Renders missing icons of the symbols in symbols.yaml with latex and dvisvgm.
+
+Options:
+  --check              only check that all icons exist and are listed as resources
+  --all                render the icons of all symbols, not only missing ones
+  --prune              remove icons of symbols which are not in symbols.yaml
+  --root DIR           root of the repository, defaults to the one of this tool
+  -h, --help           print this help";
+
+#[derive(Debug)]
+struct Options {
+    check: bool,
+    all: bool,
+    prune: bool,
+    root: PathBuf,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        check: false,
+        all: false,
+        prune: false,
+        root: Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => options.check = true,
+            "--all" => options.all = true,
+            "--prune" => options.prune = true,
+            "--root" => options.root = args.next().ok_or("missing value for --root")?.into(),
+            "-h" | "--help" => return Err(USAGE.to_owned()),
+            _ => return Err(format!("unknown argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+/// Renders the icons of the symbols into `icons`, using all cores.
+///
+/// Returns the symbols that failed to render, with the reason.
+fn render_all<'a>(symbols: &[&'a Symbol], icons: &Path) -> Vec<(&'a Symbol, String)> {
+    let next = AtomicUsize::new(0);
+    let failed = Mutex::new(Vec::new());
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let temp = std::env::temp_dir().join(format!("render-icons-{}", std::process::id()));
+
+    std::thread::scope(|scope| {
+        for thread in 0..threads {
+            let dir = temp.join(thread.to_string());
+            let (next, failed) = (&next, &failed);
+            scope.spawn(move || {
+                while let Some(symbol) = symbols.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = render::render(symbol, &dir).and_then(|svg| {
+                        std::fs::write(icons.join(symbol.icon()), svg).map_err(|e| e.to_string())
+                    });
+                    match result {
+                        Ok(()) => eprintln!("rendered {}", symbol.command),
+                        Err(err) => failed.lock().unwrap().push((*symbol, err)),
+                    }
+                }
+            });
+        }
+    });
+
+    let _ = std::fs::remove_dir_all(temp);
+    failed.into_inner().unwrap()
+}
+
+fn run(options: Options) -> Result<bool, String> {
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))
+    };
+    let (symbols, _) = symbols::parse(&read(&options.root.join("symbols.yaml"))?);
+    let icons_dir = options.root.join("data/resources/icons/symbols");
+    let resources_file = options.root.join("data/resources/resources.gresource.xml");
+
+    let existing: HashSet<String> = std::fs::read_dir(&icons_dir)
+        .map_err(|err| format!("failed to read {}: {}", icons_dir.display(), err))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    let expected: HashSet<String> = symbols.iter().map(Symbol::icon).collect();
+    let mut orphans: Vec<&String> = existing.difference(&expected).collect();
+    orphans.sort();
+    let missing: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|symbol| !existing.contains(&symbol.icon()))
+        .collect();
+
+    let mut ok = true;
+    if options.check {
+        for symbol in &missing {
+            eprintln!("missing icon of {} ({})", symbol.command, symbol.icon());
+            ok = false;
+        }
+        for icon in &orphans {
+            eprintln!("icon {} is not used by any symbol", icon);
+            ok = false;
+        }
+        let resources = read(&resources_file)?;
+        let icons: Vec<String> = symbols
+            .iter()
+            .map(Symbol::icon)
+            .filter(|icon| existing.contains(icon))
+            .collect();
+        if resources::update(&resources, &icons)? != resources {
+            eprintln!("icons listed in {} are outdated", resources_file.display());
+            ok = false;
+        }
+        return Ok(ok);
+    }
+
+    let render: Vec<&Symbol> = if options.all {
+        symbols.iter().collect()
+    } else {
+        missing
+    };
+    for (symbol, err) in render_all(&render, &icons_dir) {
+        eprintln!("failed to render {}: {}", symbol.command, err);
+        ok = false;
+    }
+
+    for icon in orphans {
+        if options.prune {
+            std::fs::remove_file(icons_dir.join(icon))
+                .map_err(|err| format!("failed to remove {}: {}", icon, err))?;
+            eprintln!("removed {}", icon);
+        } else {
+            eprintln!(
+                "icon {} is not used by any symbol, remove it with --prune",
+                icon
+            );
+        }
+    }
+
+    // list the icons in the order of the symbols, so new ones show up next to related symbols
+    let icons: Vec<String> = symbols
+        .iter()
+        .map(Symbol::icon)
+        .filter(|icon| icons_dir.join(icon).exists())
+        .collect();
+    let resources = resources::update(&read(&resources_file)?, &icons)?;
+    std::fs::write(&resources_file, resources)
+        .map_err(|err| format!("failed to write {}: {}", resources_file.display(), err))?;
+    Ok(ok)
+}
+
+fn main() -> ExitCode {
+    let result = parse_args(std::env::args().skip(1))
+        .and_then(|options| run(options).map_err(|err| format!("error: {}", err)));
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
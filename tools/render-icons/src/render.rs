@@ -0,0 +1,156 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::symbols::Symbol;
+
+/// Size of the icons in pixels.
+const SIZE: &str = "64";
+
+/// Renders the symbol to an SVG, using `dir` for the intermediate files.
+///
+/// The document is compiled with `latex` and converted with `dvisvgm`, which both have to be
+/// on the `PATH`. Glyphs are converted to paths, so the icons do not depend on fonts.
+pub fn render(symbol: &Symbol, dir: &Path) -> Result<String, String> {
+    std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    std::fs::write(dir.join("symbol.tex"), document(symbol)).map_err(|err| err.to_string())?;
+
+    run(Command::new("latex").current_dir(dir).args([
+        "-interaction=nonstopmode",
+        "-halt-on-error",
+        "symbol.tex",
+    ]))?;
+    run(Command::new("dvisvgm").current_dir(dir).args([
+        "--no-fonts=1",
+        "--exact-bbox",
+        "--output=symbol.svg",
+        "symbol.dvi",
+    ]))?;
+
+    let svg = std::fs::read_to_string(dir.join("symbol.svg")).map_err(|err| err.to_string())?;
+    resize(&svg)
+}
+
+/// Returns a minimal document containing only the symbol.
+///
+/// Symbols available in math and text mode are rendered in math mode.
+fn document(symbol: &Symbol) -> String {
+    let package = if symbol.package == "latex2e" {
+        String::new()
+    } else {
+        format!("\\usepackage{{{}}}\n", symbol.package)
+    };
+    let command = if symbol.math_mode {
+        format!("${}$", symbol.command)
+    } else {
+        symbol.command.clone()
+    };
+    format!(
+        "\\documentclass[10pt]{{article}}\n\\usepackage[utf8]{{inputenc}}\n\\usepackage[{}]{{fontenc}}\n{}\\pagestyle{{empty}}\n\\begin{{document}}\n{}\n\\end{{document}}\n",
+        symbol.font_encoding, package, command
+    )
+}
+
+/// Runs the command, returning the end of its output if it fails.
+fn run(command: &mut Command) -> Result<(), String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command
+        .output()
+        .map_err(|err| format!("failed to run {}: {}", program, err))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let log: Vec<&str> = stdout.lines().chain(stderr.lines()).collect();
+    Err(format!(
+        "{} failed:\n{}",
+        program,
+        log[log.len().saturating_sub(10)..].join("\n")
+    ))
+}
+
+/// Removes everything before the root element and sets its size to the size of the icons,
+/// keeping the aspect ratio through its `viewBox`.
+fn resize(svg: &str) -> Result<String, String> {
+    let start = svg.find("<svg").ok_or("missing svg element")?;
+    let svg = &svg[start..];
+    let end = svg.find('>').ok_or("unterminated svg element")?;
+    let (tag, content) = svg.split_at(end);
+
+    let tag = set_attribute(tag, "width", SIZE)?;
+    let tag = set_attribute(&tag, "height", SIZE)?;
+    Ok(format!("{}{}", tag, content.trim_end()))
+}
+
+/// Replaces the value of the attribute `name` of the start tag.
+fn set_attribute(tag: &str, name: &str, value: &str) -> Result<String, String> {
+    let prefix = format!(" {}='", name);
+    let (start, quote) = tag
+        .find(&format!(" {}=\"", name))
+        .map(|start| (start, '"'))
+        .or_else(|| tag.find(&prefix).map(|start| (start, '\'')))
+        .ok_or_else(|| format!("missing {} attribute", name))?;
+
+    let value_start = start + prefix.len();
+    let value_end = tag[value_start..]
+        .find(quote)
+        .map(|end| value_start + end)
+        .ok_or_else(|| format!("unterminated {} attribute", name))?;
+    Ok(format!(
+        "{}{}{}",
+        &tag[..value_start],
+        value,
+        &tag[value_end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document() {
+        let symbol = Symbol {
+            command: "\\Mapsto".to_owned(),
+            package: "stmaryrd".to_owned(),
+            math_mode: true,
+            ..Symbol::default()
+        };
+        let latex = document(&symbol);
+        assert!(latex.contains("\\usepackage[OT1]{fontenc}\n\\usepackage{stmaryrd}\n"));
+        assert!(latex.contains("\n$\\Mapsto$\n"));
+
+        let latex = document(&Symbol {
+            command: "\\P".to_owned(),
+            ..Symbol::default()
+        });
+        assert!(!latex.contains("latex2e"));
+        assert!(latex.contains("\n\\P\n"));
+    }
+
+    #[test]
+    fn test_resize() {
+        let svg = "<?xml version='1.0' encoding='UTF-8'?>
+<!-- This file was generated by dvisvgm 3.2 -->
+<svg version='1.1' xmlns='http://www.w3.org/2000/svg' width='5.469pt' height='6.781pt' viewBox='0 0 5.469 6.781'>
+<path d='M5.516-6.781H.328z'/>
+</svg>
+";
+        assert_eq!(
+            resize(svg).unwrap(),
+            "<svg version='1.1' xmlns='http://www.w3.org/2000/svg' width='64' height='64' viewBox='0 0 5.469 6.781'>
+<path d='M5.516-6.781H.328z'/>
+</svg>"
+        );
+    }
+
+    #[test]
+    fn test_set_attribute() {
+        assert_eq!(
+            set_attribute("<svg width=\"1pt\" stroke-width=\"2\"", "width", "64").unwrap(),
+            "<svg width=\"64\" stroke-width=\"2\""
+        );
+        assert!(set_attribute("<svg stroke-width='2'", "width", "64").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+/// Comment after which the icons of the symbols are listed in the resource file.
+const MARKER: &str = "<!-- Generated preview of the latex symbols -->";
+
+/// Replaces the icons listed after the [`MARKER`] in the resource file with `icons`,
+/// which are file names in `data/resources/icons/symbols`.
+pub fn update(xml: &str, icons: &[String]) -> Result<String, String> {
+    let start = xml
+        .find(MARKER)
+        .map(|start| start + MARKER.len())
+        .ok_or_else(|| format!("missing `{}` in the resource file", MARKER))?;
+    let end = xml[start..]
+        .find("</gresource>")
+        .map(|end| start + end)
+        .ok_or("missing end of the icon resources")?;
+
+    let files: String = icons
+        .iter()
+        .map(|icon| {
+            format!(
+                "\n\t<file compressed=\"true\" alias=\"{0}\">icons/symbols/{0}</file>",
+                icon
+            )
+        })
+        .collect();
+    Ok(format!("{}{}\n{}", &xml[..start], files, &xml[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let xml = r#"<gresources>
+<gresource prefix="/icons">
+    <file alias="edit-symbolic.svg">icons/edit-symbolic.svg</file>
+    <!-- Generated preview of the latex symbols -->
+	<file compressed="true" alias="b-symbolic.svg">icons/b-symbolic.svg</file>
+</gresource>
+</gresources>
+"#;
+        let icons = vec!["a-symbolic.svg".to_owned(), "c-symbolic.svg".to_owned()];
+        assert_eq!(
+            update(xml, &icons).unwrap(),
+            r#"<gresources>
+<gresource prefix="/icons">
+    <file alias="edit-symbolic.svg">icons/edit-symbolic.svg</file>
+    <!-- Generated preview of the latex symbols -->
+	<file compressed="true" alias="a-symbolic.svg">icons/symbols/a-symbolic.svg</file>
+	<file compressed="true" alias="c-symbolic.svg">icons/symbols/c-symbolic.svg</file>
+</gresource>
+</gresources>
+"#
+        );
+        assert_eq!(
+            update(&update(xml, &icons).unwrap(), &icons),
+            update(xml, &icons)
+        );
+        assert!(update("<gresources></gresources>", &icons).is_err());
+    }
+}